@@ -1,9 +1,10 @@
 //! Omega Compute — Stylus smart contract for iterated keccak256 benchmarking.
 //!
 //! This contract runs N iterations of keccak256 hashing with minimal storage
-//! (1 counter increment per call) to isolate WASM computation cost from storage
-//! overhead. Ink metering makes loop/hash operations dramatically cheaper than
-//! EVM opcodes, so this benchmark should show a clear Stylus gas advantage.
+//! (1 counter increment and 1 result slot per call) to isolate WASM
+//! computation cost from storage overhead. Ink metering makes loop/hash
+//! operations dramatically cheaper than EVM opcodes, so this benchmark should
+//! show a clear Stylus gas advantage.
 
 #![cfg_attr(not(any(feature = "export-abi", test)), no_main)]
 
@@ -11,18 +12,129 @@ extern crate alloc;
 
 use alloc::vec::Vec;
 use stylus_sdk::prelude::*;
-use stylus_sdk::storage::StorageU256;
-use stylus_sdk::alloy_primitives::{B256, U256};
-use stylus_sdk::{crypto, evm};
-use alloy_sol_types::sol;
+use stylus_sdk::storage::{StorageAddress, StorageB256, StorageBool, StorageMap, StorageU256};
+use stylus_sdk::abi::Bytes;
+use stylus_sdk::alloy_primitives::{Address, B256, U256};
+use stylus_sdk::{block, contract, crypto, evm, msg};
+use alloy_sol_types::{sol, SolError, SolValue};
+use sha2::{Digest, Sha256};
 
 // ---------------------------------------------------------------------------
-// Events
+// Events and errors
 // ---------------------------------------------------------------------------
 
 sol! {
-    /// Emitted when a compute_hash call completes.
-    event ComputeCompleted(uint256 indexed iterations, bytes32 finalHash);
+    /// Emitted when a compute_hash call, or any other run, completes.
+    /// `algorithm` is `ALG_KECCAK256` (0), `ALG_SHA256` (1), or
+    /// `ALG_KECCAK256_CONCAT` (2) for `compute_concat`; `writes` is the number
+    /// of extra storage writes performed (non-zero only for
+    /// `compute_and_store`); `reads` is the number of benchmark storage reads
+    /// (non-zero only for `compute_with_reads`, `compute_warm` and
+    /// `compute_cold`); `gasUsed` is the gas consumed by the hash loop
+    /// (non-zero only for `compute_hash_metered`); `accumulator` is the final
+    /// modular-arithmetic accumulator (non-zero only for `compute_mixed`).
+    /// `caller` is the account that triggered the run, indexed so runs can be
+    /// filtered per wallet.
+    event ComputeCompleted(uint256 indexed iterations, address indexed caller, bytes32 finalHash, uint8 algorithm, uint256 writes, uint256 reads, uint256 gasUsed, uint256 accumulator);
+
+    /// Emitted by `compute_hash_checkpoints` after `iterationIndex` iterations
+    /// with the gas remaining at that point.
+    event Checkpoint(uint256 iterationIndex, uint256 gasLeft);
+
+    /// Emitted when the owner resets `call_count` between benchmark sessions.
+    event CounterReset(address indexed by);
+
+    /// The requested hash algorithm is not supported.
+    error UnknownAlgorithm(uint8 alg);
+
+    /// A trace would return more snapshots than a single call allows.
+    error TraceTooLarge(uint256 requested, uint256 max);
+
+    /// `compute_and_store` was asked for more storage writes than allowed.
+    error WritesTooLarge(uint256 requested, uint256 max);
+
+    /// The caller is not the contract owner.
+    error NotOwner(address caller);
+
+    /// `init` was called after the owner had already been set.
+    error AlreadyInitialized();
+
+    /// An address argument was the zero address.
+    error ZeroAddress();
+
+    /// `compute_alloc` was asked for a larger buffer than allowed.
+    error AllocTooLarge(uint256 requested, uint256 max);
+
+    /// `compute_batch` was given more entries than a single call allows.
+    error BatchTooLarge(uint256 requested, uint256 max);
+
+    /// The requested iteration count exceeds `max_iterations`.
+    error IterationsTooLarge(uint256 requested, uint256 max);
+
+    /// `compute_hash_checkpoints` was asked for more checkpoints than allowed.
+    error CheckpointsTooLarge(uint256 requested, uint256 max);
+
+    /// `compute_with_reads` was asked for more reads than `MAX_READS`.
+    error ReadsTooLarge(uint256 requested, uint256 max);
+
+    /// `compute_warm` or `compute_cold` was asked for more storage reads than
+    /// `MAX_PROBE_READS`.
+    error ProbeTooLarge(uint256 requested, uint256 max);
+}
+
+/// Seed used by `compute_hash`; shared with the Solidity benchmark contract.
+const DEFAULT_SEED: &str = "stylus-compute-bench";
+
+/// Maximum number of snapshots returned by `compute_hash_trace`.
+const MAX_TRACE_LEN: u64 = 256;
+
+/// Maximum number of storage writes accepted by `compute_and_store`.
+const MAX_WRITES: u64 = 500;
+
+/// Maximum buffer size in bytes accepted by `compute_alloc` (16 WASM pages).
+const MAX_ALLOC_BYTES: u64 = 1 << 20;
+
+/// Number of pre-populated slots read by `compute_with_reads`, and so the
+/// maximum `reads` per call (each slot is read at most once per call).
+const MAX_READS: u64 = 128;
+
+/// Iteration cap for `compute_concat`, whose hashed data grows quadratically.
+const MAX_CONCAT_ITERATIONS: u64 = 512;
+
+/// Maximum loop length for `compute_warm` and `compute_cold`, each iteration
+/// being one storage read (about 2.1M gas of cold reads at the cap).
+const MAX_PROBE_READS: u64 = 1_000;
+
+/// Maximum number of sub-runs accepted by `compute_batch`.
+const MAX_BATCH_SIZE: u64 = 32;
+
+/// Iteration cap while `max_iterations` is unset.
+const DEFAULT_MAX_ITERATIONS: u64 = 10_000_000;
+
+/// Maximum number of `Checkpoint` events per `compute_hash_checkpoints` call.
+const MAX_CHECKPOINTS: u64 = 100;
+
+/// Algorithm selector for keccak256 (via the `crypto::keccak` host function).
+const ALG_KECCAK256: u8 = 0;
+
+/// Algorithm selector for sha256 (computed in WASM by the `sha2` crate).
+const ALG_SHA256: u8 = 1;
+
+/// Mode reported by `compute_concat`: keccak256 over a growing buffer. Not
+/// accepted by `compute_hash_alg`.
+const ALG_KECCAK256_CONCAT: u8 = 2;
+
+/// Modular multiplications per iteration in `compute_mixed`.
+const MIXED_MULS_PER_ROUND: u64 = 4;
+
+/// Per-run figures reported in `ComputeCompleted` beyond the hash itself.
+/// Entry points fill in only the fields they measure.
+#[derive(Default)]
+struct RunStats {
+    writes: U256,
+    reads: U256,
+    gas_used: U256,
+    accumulator: U256,
 }
 
 // ---------------------------------------------------------------------------
@@ -33,10 +145,30 @@ sol! {
 ///
 /// Storage layout:
 /// - `call_count`: number of times compute_hash has been called (1 SSTORE per call)
+/// - `calls_by_sender`: caller address -> number of runs it triggered, except via compute_hash (1 SSTORE per such call)
+/// - `last_hash`: final hash of the most recent run (1 SSTORE per call, single slot)
+/// - `slots`: slot index -> value written by `compute_and_store` (`writes` SSTOREs per call)
+/// - `next_slot`: next unused index in `slots` (1 SSTORE per `compute_and_store` call)
+/// - `owner`: admin address allowed to reset counters, set once via `init`
+/// - `total_iterations`: sum of `iterations` over all runs except via compute_hash, saturating (1 SSTORE per such call)
+/// - `max_iterations`: per-run iteration cap (0 = `DEFAULT_MAX_ITERATIONS`)
+/// - `read_slots`: slot index -> fixed value read by `compute_with_reads` (`MAX_READS` slots, written once)
+/// - `read_slots_ready`: whether `read_slots` has been populated
+/// - `probe_slots`: slot index -> value read by `compute_warm` (1 SSTORE per call) and `compute_cold`
 #[storage]
 #[entrypoint]
 pub struct ComputeContract {
     call_count: StorageU256,
+    calls_by_sender: StorageMap<Address, StorageU256>,
+    last_hash: StorageB256,
+    slots: StorageMap<U256, StorageU256>,
+    next_slot: StorageU256,
+    owner: StorageAddress,
+    total_iterations: StorageU256,
+    max_iterations: StorageU256,
+    read_slots: StorageMap<U256, StorageU256>,
+    read_slots_ready: StorageBool,
+    probe_slots: StorageMap<U256, StorageU256>,
 }
 
 // ---------------------------------------------------------------------------
@@ -51,35 +183,695 @@ impl ComputeContract {
     ///   hash_0 = keccak256("stylus-compute-bench")
     ///   hash_i = keccak256(hash_{i-1})
     ///
-    /// Increments `call_count` by 1 (single SSTORE) and emits `ComputeCompleted`.
-    /// Returns the final hash after all iterations.
+    /// Increments `call_count` and records `last_hash`, so the benchmark's
+    /// storage cost is effectively two single-slot SSTOREs (ComputeEVM.sol
+    /// only increments its counter), then emits `ComputeCompleted`. Unlike
+    /// the other entry points it leaves `calls_by_sender` and
+    /// `total_iterations` alone, keeping those writes off the benchmarked
+    /// path. Returns the final hash after all iterations.
+    ///
+    /// # Errors
+    /// Reverts with `IterationsTooLarge` if `iterations` exceeds
+    /// `max_iterations`, as do all other entry points that run the chain.
     pub fn compute_hash(&mut self, iterations: U256) -> Result<B256, Vec<u8>> {
-        // Start from fixed seed
-        let mut hash: [u8; 32] = crypto::keccak(b"stylus-compute-bench").into();
+        self.require_iterations(iterations)?;
+        let final_hash = keccak_chain(DEFAULT_SEED.as_bytes(), iterations);
+        self.finish_run(iterations, final_hash, ALG_KECCAK256, RunStats::default());
+        Ok(final_hash)
+    }
 
-        // Iterate — this is pure WASM computation priced in ink
-        let n = iterations.saturating_to::<u64>();
+    /// Run `compute_hash` and also return the block number and chain ID it
+    /// ran in, so off-chain collectors can tag results without another RPC
+    /// call. Storage writes and `ComputeCompleted` match `compute_hash`.
+    ///
+    /// # Errors
+    /// Same as `compute_hash`.
+    pub fn compute_hash_ctx(&mut self, iterations: U256) -> Result<(B256, U256, U256), Vec<u8>> {
+        let final_hash = self.compute_hash(iterations)?;
+        let number = U256::from(block::number());
+        let chain_id = U256::from(block::chainid());
+        Ok((final_hash, number, chain_id))
+    }
+
+    /// Run `iterations` rounds of the hash selected by `alg` from the fixed
+    /// seed: keccak256 (`ALG_KECCAK256` = 0) or sha256 (`ALG_SHA256` = 1).
+    ///
+    /// The chain has the same shape as `compute_hash`, and the chosen
+    /// algorithm is reported in `ComputeCompleted`.
+    ///
+    /// # Errors
+    /// Reverts with `UnknownAlgorithm` for any other selector.
+    pub fn compute_hash_alg(&mut self, alg: u8, iterations: U256) -> Result<B256, Vec<u8>> {
+        self.require_iterations(iterations)?;
+        let final_hash = match alg {
+            ALG_KECCAK256 => keccak_chain(DEFAULT_SEED.as_bytes(), iterations),
+            ALG_SHA256 => sha256_chain(DEFAULT_SEED.as_bytes(), iterations),
+            _ => return Err(UnknownAlgorithm { alg }.abi_encode()),
+        };
+        self.record_run(iterations, final_hash, alg);
+        Ok(final_hash)
+    }
+
+    /// Run `iterations` rounds of keccak256 hashing starting from a caller-
+    /// supplied seed.
+    ///
+    /// Identical to `compute_hash` except that `hash_0 = keccak256(seed)`, so
+    /// distinct workloads can be benchmarked without reusing results.
+    pub fn compute_hash_seeded(&mut self, seed: Bytes, iterations: U256) -> Result<B256, Vec<u8>> {
+        self.require_iterations(iterations)?;
+        let final_hash = keccak_chain(&seed, iterations);
+        self.record_run(iterations, final_hash, ALG_KECCAK256);
+        Ok(final_hash)
+    }
+
+    /// Run the `compute_hash` chain and measure the gas it consumes.
+    ///
+    /// Reads `evm::gas_left()` immediately before and after the loop, so the
+    /// reported amount excludes calldata, storage, and logging costs. Returns
+    /// the final hash and the consumed gas, which is also reported as
+    /// `gasUsed` in `ComputeCompleted`.
+    #[allow(deprecated)]
+    pub fn compute_hash_metered(&mut self, iterations: U256) -> Result<(B256, U256), Vec<u8>> {
+        self.require_iterations(iterations)?;
+        let before = evm::gas_left();
+        let final_hash = keccak_chain(DEFAULT_SEED.as_bytes(), iterations);
+        let gas_used = U256::from(before.saturating_sub(evm::gas_left()));
+
+        let stats = RunStats {
+            gas_used,
+            ..RunStats::default()
+        };
+        self.record_run_with(iterations, final_hash, ALG_KECCAK256, stats);
+        Ok((final_hash, gas_used))
+    }
+
+    /// Run the `compute_hash` chain, then perform `writes` extra SSTOREs into
+    /// fresh slots of `slots`.
+    ///
+    /// Each write goes to the next unused slot index, so every write is a
+    /// zero-to-non-zero SSTORE regardless of prior calls. Sweeping
+    /// `iterations` against `writes` measures the storage/compute ratio
+    /// without redeploying.
+    ///
+    /// # Errors
+    /// Reverts with `WritesTooLarge` if `writes` exceeds `MAX_WRITES`.
+    pub fn compute_and_store(&mut self, iterations: U256, writes: U256) -> Result<B256, Vec<u8>> {
+        self.require_iterations(iterations)?;
+        if writes > U256::from(MAX_WRITES) {
+            return Err(WritesTooLarge {
+                requested: writes,
+                max: U256::from(MAX_WRITES),
+            }
+            .abi_encode());
+        }
+
+        let final_hash = keccak_chain(DEFAULT_SEED.as_bytes(), iterations);
+
+        let value = U256::from_be_bytes(final_hash.0) | U256::from(1);
+        let start = self.next_slot.get();
+        let end = start + writes;
+        let mut slot = start;
+        while slot < end {
+            self.slots.setter(slot).set(value);
+            slot += U256::from(1);
+        }
+        self.next_slot.set(end);
+
+        let stats = RunStats {
+            writes,
+            ..RunStats::default()
+        };
+        self.record_run_with(iterations, final_hash, ALG_KECCAK256, stats);
+        Ok(final_hash)
+    }
+
+    /// Run the `compute_hash` chain, then perform `reads` SLOADs from a fixed
+    /// set of slots, folding each value into the hash with one more keccak.
+    ///
+    /// The `MAX_READS` slots are populated on the first call, which therefore
+    /// also pays for those writes and reads back cached values; benchmark
+    /// from the second call on. Each slot is read at most once per call so
+    /// every read reaches storage rather than the SDK's storage cache. The
+    /// number of reads is reported as `reads` in `ComputeCompleted`.
+    ///
+    /// # Errors
+    /// - `ReadsTooLarge` if `reads` exceeds `MAX_READS`.
+    /// - `IterationsTooLarge` if `iterations` exceeds `max_iterations`.
+    pub fn compute_with_reads(&mut self, iterations: U256, reads: U256) -> Result<B256, Vec<u8>> {
+        self.require_iterations(iterations)?;
+        if reads > U256::from(MAX_READS) {
+            return Err(ReadsTooLarge {
+                requested: reads,
+                max: U256::from(MAX_READS),
+            }
+            .abi_encode());
+        }
+        if !self.read_slots_ready.get() {
+            for i in 0..MAX_READS {
+                let slot = U256::from(i);
+                let value = U256::from_be_bytes(crypto::keccak(slot.to_be_bytes::<32>()).0);
+                self.read_slots.setter(slot).set(value);
+            }
+            self.read_slots_ready.set(true);
+        }
+
+        let mut hash = keccak_chain(DEFAULT_SEED.as_bytes(), iterations).0;
+        for i in 0..reads.to::<u64>() {
+            let value = self.read_slots.get(U256::from(i));
+            hash = crypto::keccak([hash, value.to_be_bytes()].concat()).into();
+        }
+
+        let final_hash = B256::from(hash);
+        let stats = RunStats {
+            reads,
+            ..RunStats::default()
+        };
+        self.record_run_with(iterations, final_hash, ALG_KECCAK256, stats);
+        Ok(final_hash)
+    }
+
+    /// Allocate a `bytes`-long buffer, then `iterations` times fill it with
+    /// the previous hash (repeated) and hash the whole buffer.
+    ///
+    ///   hash_0 = keccak256("stylus-compute-bench")
+    ///   hash_i = keccak256(hash_{i-1} repeated to `bytes` bytes)
+    ///
+    /// Exercises WASM memory growth and per-byte hashing cost under ink
+    /// pricing. Bookkeeping and events are the same as `compute_hash`.
+    ///
+    /// # Errors
+    /// Reverts with `AllocTooLarge` if `bytes` exceeds `MAX_ALLOC_BYTES`.
+    pub fn compute_alloc(&mut self, bytes: U256, iterations: U256) -> Result<B256, Vec<u8>> {
+        self.require_iterations(iterations)?;
+        if bytes > U256::from(MAX_ALLOC_BYTES) {
+            return Err(AllocTooLarge {
+                requested: bytes,
+                max: U256::from(MAX_ALLOC_BYTES),
+            }
+            .abi_encode());
+        }
+
+        let mut buffer = alloc::vec![0u8; bytes.to::<usize>()];
+        let mut hash: [u8; 32] = crypto::keccak(DEFAULT_SEED).into();
+        for _ in 0..iterations.saturating_to::<u64>() {
+            for chunk in buffer.chunks_mut(32) {
+                chunk.copy_from_slice(&hash[..chunk.len()]);
+            }
+            hash = crypto::keccak(&buffer).into();
+        }
+
+        let final_hash = B256::from(hash);
+        self.record_run(iterations, final_hash, ALG_KECCAK256);
+        Ok(final_hash)
+    }
+
+    /// Write `slot` once, then read it back `iterations` times, folding each
+    /// value into a keccak chain. Every read after the write hits an
+    /// already-accessed (warm) slot; compare with `compute_cold`.
+    ///
+    ///   hash_0 = keccak256("stylus-compute-bench")
+    ///   hash_i = keccak256(hash_{i-1} ++ probe_slots[slot])
+    ///
+    /// Each iteration issues a fresh storage load through the SDK accessor.
+    /// Reports `iterations` as both the iteration and `reads` counts in
+    /// `ComputeCompleted`.
+    ///
+    /// # Errors
+    /// - `ProbeTooLarge` if `iterations` exceeds `MAX_PROBE_READS`.
+    /// - `IterationsTooLarge` if `iterations` exceeds `max_iterations`.
+    pub fn compute_warm(&mut self, iterations: U256, slot: U256) -> Result<B256, Vec<u8>> {
+        self.require_iterations(iterations)?;
+        self.require_probe_reads(iterations)?;
+        let mut hash: [u8; 32] = crypto::keccak(DEFAULT_SEED).into();
+        self.probe_slots
+            .setter(slot)
+            .set(U256::from_be_bytes(hash) | U256::from(1));
+
+        for _ in 0..iterations.to::<u64>() {
+            let value = self.probe_slots.get(slot);
+            hash = crypto::keccak([hash, value.to_be_bytes()].concat()).into();
+        }
+        Ok(self.record_probe(iterations, hash))
+    }
+
+    /// The cold counterpart of `compute_warm`: read a different slot each
+    /// iteration (`probe_slots[i]` for `i` in `0..iterations`), so every read
+    /// is the transaction's first access to that slot. Nothing is written
+    /// beyond the usual bookkeeping.
+    ///
+    ///   hash_i = keccak256(hash_{i-1} ++ probe_slots[i - 1])
+    ///
+    /// # Errors
+    /// - `ProbeTooLarge` if `iterations` exceeds `MAX_PROBE_READS`.
+    /// - `IterationsTooLarge` if `iterations` exceeds `max_iterations`.
+    pub fn compute_cold(&mut self, iterations: U256) -> Result<B256, Vec<u8>> {
+        self.require_iterations(iterations)?;
+        self.require_probe_reads(iterations)?;
+        let mut hash: [u8; 32] = crypto::keccak(DEFAULT_SEED).into();
+        for i in 0..iterations.to::<u64>() {
+            let value = self.probe_slots.get(U256::from(i));
+            hash = crypto::keccak([hash, value.to_be_bytes()].concat()).into();
+        }
+        Ok(self.record_probe(iterations, hash))
+    }
+
+    /// Run the `compute_hash` chain, and after each keccak fold the new hash
+    /// into a `U256` accumulator with `MIXED_MULS_PER_ROUND` modular
+    /// multiplications, modelling contracts that mix hashing with big-integer
+    /// math:
+    ///
+    ///   acc_0 = 1
+    ///   acc   = acc * hash_i + 1 (mod p), repeated per round
+    ///
+    /// where `p` is the secp256k1 field prime. Returns the final hash (equal
+    /// to `compute_hash`'s) and accumulator; both are reported in
+    /// `ComputeCompleted`.
+    ///
+    /// # Errors
+    /// Reverts with `IterationsTooLarge` if `iterations` exceeds
+    /// `max_iterations`.
+    pub fn compute_mixed(&mut self, iterations: U256) -> Result<(B256, U256), Vec<u8>> {
+        self.require_iterations(iterations)?;
+        let modulus = U256::MAX - U256::from(0x1_0000_03d0_u64);
+        let one = U256::from(1);
+
+        let mut hash: [u8; 32] = crypto::keccak(DEFAULT_SEED).into();
+        let mut accumulator = one;
+        for _ in 0..iterations.saturating_to::<u64>() {
+            hash = crypto::keccak(hash).into();
+            let x = U256::from_be_bytes(hash);
+            for _ in 0..MIXED_MULS_PER_ROUND {
+                accumulator = accumulator.mul_mod(x, modulus).add_mod(one, modulus);
+            }
+        }
+
+        let final_hash = B256::from(hash);
+        let stats = RunStats {
+            accumulator,
+            ..RunStats::default()
+        };
+        self.record_run_with(iterations, final_hash, ALG_KECCAK256, stats);
+        Ok((final_hash, accumulator))
+    }
+
+    /// Hash a buffer that grows by one hash per iteration, rehashing the
+    /// whole buffer each time:
+    ///
+    ///   buf_0  = keccak256("stylus-compute-bench")
+    ///   hash_i = keccak256(buf_{i-1}), buf_i = buf_{i-1} ++ hash_i
+    ///
+    /// The data hashed grows quadratically with `iterations`, against the
+    /// linear `compute_hash` chain, to compare memory-heavy hashing cost
+    /// under ink pricing. Returns the last hash (the seed hash for 0
+    /// iterations) and reports `ALG_KECCAK256_CONCAT` in `ComputeCompleted`.
+    ///
+    /// # Errors
+    /// Reverts with `IterationsTooLarge` if `iterations` exceeds
+    /// `MAX_CONCAT_ITERATIONS` or `max_iterations`.
+    pub fn compute_concat(&mut self, iterations: U256) -> Result<B256, Vec<u8>> {
+        self.require_iterations(iterations)?;
+        let max = U256::from(MAX_CONCAT_ITERATIONS);
+        if iterations > max {
+            return Err(IterationsTooLarge {
+                requested: iterations,
+                max,
+            }
+            .abi_encode());
+        }
+
+        let n = iterations.to::<usize>();
+        let mut hash: [u8; 32] = crypto::keccak(DEFAULT_SEED).into();
+        let mut buffer = Vec::with_capacity((n + 1) * 32);
+        buffer.extend_from_slice(&hash);
         for _ in 0..n {
-            hash = crypto::keccak(&hash).into();
+            hash = crypto::keccak(&buffer).into();
+            buffer.extend_from_slice(&hash);
+        }
+
+        let final_hash = B256::from(hash);
+        self.record_run(iterations, final_hash, ALG_KECCAK256_CONCAT);
+        Ok(final_hash)
+    }
+
+    /// Run `compute_hash` once per entry of `iter_list`, returning the final
+    /// hashes in order.
+    ///
+    /// Every sub-run starts from the fixed seed, so each result equals a
+    /// standalone `compute_hash` with the same count. Each sub-run increments
+    /// the counters and emits its own `ComputeCompleted`, amortizing the base
+    /// transaction cost across an iteration sweep.
+    ///
+    /// # Errors
+    /// Reverts with `BatchTooLarge` if `iter_list` has more than
+    /// `MAX_BATCH_SIZE` entries.
+    pub fn compute_batch(&mut self, iter_list: Vec<U256>) -> Result<Vec<B256>, Vec<u8>> {
+        if iter_list.len() as u64 > MAX_BATCH_SIZE {
+            return Err(BatchTooLarge {
+                requested: U256::from(iter_list.len()),
+                max: U256::from(MAX_BATCH_SIZE),
+            }
+            .abi_encode());
+        }
+
+        for &iterations in &iter_list {
+            self.require_iterations(iterations)?;
+        }
+
+        let mut hashes = Vec::with_capacity(iter_list.len());
+        for iterations in iter_list {
+            let final_hash = keccak_chain(DEFAULT_SEED.as_bytes(), iterations);
+            self.record_run(iterations, final_hash, ALG_KECCAK256);
+            hashes.push(final_hash);
+        }
+        Ok(hashes)
+    }
+
+    /// Run the `compute_hash` chain, emitting `checkpoints` evenly spaced
+    /// `Checkpoint` events with the gas left at each point.
+    ///
+    /// The k-th checkpoint (1-based) is emitted after
+    /// `k * iterations / checkpoints` iterations, so the last one marks the end
+    /// of the loop. The logs trace the gas curve across the run. Returns the
+    /// same final hash as `compute_hash`.
+    ///
+    /// # Errors
+    /// - `CheckpointsTooLarge` if `checkpoints` exceeds `MAX_CHECKPOINTS`.
+    /// - `IterationsTooLarge` if `iterations` exceeds `max_iterations`.
+    #[allow(deprecated)]
+    pub fn compute_hash_checkpoints(
+        &mut self,
+        iterations: U256,
+        checkpoints: U256,
+    ) -> Result<B256, Vec<u8>> {
+        self.require_iterations(iterations)?;
+        if checkpoints > U256::from(MAX_CHECKPOINTS) {
+            return Err(CheckpointsTooLarge {
+                requested: checkpoints,
+                max: U256::from(MAX_CHECKPOINTS),
+            }
+            .abi_encode());
+        }
+
+        let n = iterations.saturating_to::<u64>();
+        let segments = checkpoints.to::<u64>();
+        let mut hash: [u8; 32] = crypto::keccak(DEFAULT_SEED).into();
+        let mut done = 0;
+        for k in 1..=segments {
+            // Widen before multiplying so `n * k` cannot overflow for large n.
+            let target = (u128::from(n) * u128::from(k) / u128::from(segments)) as u64;
+            hash = keccak_rounds(hash, target - done);
+            done = target;
+            evm::log(Checkpoint {
+                iterationIndex: U256::from(done),
+                gasLeft: U256::from(evm::gas_left()),
+            });
+        }
+        hash = keccak_rounds(hash, n - done);
+
+        let final_hash = B256::from(hash);
+        self.record_run(iterations, final_hash, ALG_KECCAK256);
+        Ok(final_hash)
+    }
+
+    /// Run `iterations` rounds of keccak256 from a seed bound to this chain and
+    /// deployment.
+    ///
+    ///   hash_0 = keccak256(abi.encode(chainid, address(this), "stylus-compute-bench"))
+    ///
+    /// Every deployment therefore produces a distinct chain, which exposes
+    /// results accidentally reused across environments. The event is the same
+    /// as `compute_hash`'s.
+    #[allow(deprecated)]
+    pub fn compute_hash_chain_separated(&mut self, iterations: U256) -> Result<B256, Vec<u8>> {
+        self.require_iterations(iterations)?;
+        let chain_id = U256::from(block::chainid());
+        let seed = (chain_id, contract::address(), DEFAULT_SEED).abi_encode_params();
+        let final_hash = keccak_chain(&seed, iterations);
+        self.record_run(iterations, final_hash, ALG_KECCAK256);
+        Ok(final_hash)
+    }
+
+    /// Run the same keccak256 chain as `compute_hash` without any storage
+    /// writes or events.
+    ///
+    /// Comparing its `eth_call` gas against `compute_hash` isolates the
+    /// storage and logging overhead of the state-changing path.
+    pub fn compute_hash_view(&self, iterations: U256) -> Result<B256, Vec<u8>> {
+        self.require_iterations(iterations)?;
+        Ok(keccak_chain(DEFAULT_SEED.as_bytes(), iterations))
+    }
+
+    /// Run the `compute_hash` chain and return a snapshot every `stride`
+    /// iterations, followed by the final hash if it is not already the last
+    /// snapshot.
+    ///
+    /// A `stride` of 0 returns only the final hash. Like `compute_hash_view`
+    /// this performs no storage writes, so a partial run can be cross-checked
+    /// against a full run off-chain.
+    ///
+    /// # Errors
+    /// Reverts with `TraceTooLarge` if more than `MAX_TRACE_LEN` snapshots
+    /// would be returned.
+    pub fn compute_hash_trace(&self, iterations: U256, stride: U256) -> Result<Vec<B256>, Vec<u8>> {
+        self.require_iterations(iterations)?;
+        let n = iterations.saturating_to::<u64>();
+        let stride = stride.saturating_to::<u64>();
+
+        let (full, tail) = match stride {
+            0 => (0, true),
+            _ => (n / stride, n % stride != 0 || n == 0),
+        };
+        let requested = full + u64::from(tail);
+        if requested > MAX_TRACE_LEN {
+            return Err(TraceTooLarge {
+                requested: U256::from(requested),
+                max: U256::from(MAX_TRACE_LEN),
+            }
+            .abi_encode());
+        }
+
+        let mut hash: [u8; 32] = crypto::keccak(DEFAULT_SEED).into();
+        let mut trace = Vec::with_capacity(requested as usize);
+        for _ in 0..full {
+            hash = keccak_rounds(hash, stride);
+            trace.push(B256::from(hash));
+        }
+        if tail {
+            hash = keccak_rounds(hash, n - full * stride);
+            trace.push(B256::from(hash));
+        }
+        Ok(trace)
+    }
+
+    /// Get the total number of compute_hash calls.
+    pub fn call_count(&self) -> U256 {
+        self.call_count.get()
+    }
+
+    /// Get the final hash of the most recent compute_hash run.
+    ///
+    /// Zero until the first run. Useful for checking determinism across
+    /// deployments without replaying logs.
+    pub fn last_hash(&self) -> B256 {
+        self.last_hash.get()
+    }
+
+    /// Get the total number of hash iterations requested across all runs
+    /// other than `compute_hash` and `compute_hash_ctx`, which skip this write
+    /// to keep the benchmark at two SSTOREs.
+    pub fn total_iterations(&self) -> U256 {
+        self.total_iterations.get()
+    }
+
+    /// Get the number of runs `addr` triggered through entry points other than
+    /// `compute_hash` and `compute_hash_ctx`, which skip this write to keep
+    /// the benchmark at two SSTOREs.
+    pub fn call_count_of(&self, addr: Address) -> U256 {
+        self.calls_by_sender.get(addr)
+    }
+
+    /// Set the contract owner. Can only be called once.
+    ///
+    /// # Errors
+    /// - `AlreadyInitialized` if the owner has already been set.
+    /// - `ZeroAddress` if `owner` is the zero address.
+    pub fn init(&mut self, owner: Address) -> Result<(), Vec<u8>> {
+        if !self.owner.get().is_zero() {
+            return Err(AlreadyInitialized {}.abi_encode());
+        }
+        if owner.is_zero() {
+            return Err(ZeroAddress {}.abi_encode());
+        }
+        self.owner.set(owner);
+        Ok(())
+    }
+
+    /// Get the contract owner (zero until `init` is called).
+    pub fn owner(&self) -> Address {
+        self.owner.get()
+    }
+
+    /// Reset `call_count` to zero for a fresh benchmark session and emit
+    /// `CounterReset`. Per-caller counts and `last_hash` are left as-is.
+    ///
+    /// # Errors
+    /// Reverts with `NotOwner` if the caller is not the owner, including
+    /// while no owner has been set.
+    pub fn reset_count(&mut self) -> Result<(), Vec<u8>> {
+        self.require_owner()?;
+        self.call_count.set(U256::ZERO);
+        evm::log(CounterReset { by: msg::sender() });
+        Ok(())
+    }
+
+    /// Get the maximum iteration count accepted by a single run.
+    pub fn max_iterations(&self) -> U256 {
+        let max = self.max_iterations.get();
+        if max.is_zero() {
+            U256::from(DEFAULT_MAX_ITERATIONS)
+        } else {
+            max
+        }
+    }
+
+    /// Set the maximum iteration count accepted by a single run. Zero
+    /// restores `DEFAULT_MAX_ITERATIONS`.
+    ///
+    /// # Errors
+    /// Reverts with `NotOwner` if the caller is not the owner.
+    pub fn set_max_iterations(&mut self, max: U256) -> Result<(), Vec<u8>> {
+        self.require_owner()?;
+        self.max_iterations.set(max);
+        Ok(())
+    }
+}
+
+// ---------------------------------------------------------------------------
+// Internal helpers (not exposed in the ABI)
+// ---------------------------------------------------------------------------
+
+impl ComputeContract {
+    /// Revert with `NotOwner` unless the caller is the owner. Always reverts
+    /// while no owner has been set.
+    fn require_owner(&self) -> Result<(), Vec<u8>> {
+        let caller = msg::sender();
+        let owner = self.owner.get();
+        if owner.is_zero() || caller != owner {
+            return Err(NotOwner { caller }.abi_encode());
+        }
+        Ok(())
+    }
+
+    /// Revert with `IterationsTooLarge` if `iterations` exceeds
+    /// `max_iterations`.
+    fn require_iterations(&self, iterations: U256) -> Result<(), Vec<u8>> {
+        let max = self.max_iterations();
+        if iterations > max {
+            return Err(IterationsTooLarge {
+                requested: iterations,
+                max,
+            }
+            .abi_encode());
+        }
+        Ok(())
+    }
+
+    /// Revert with `ProbeTooLarge` if a warm/cold probe of `reads` iterations
+    /// exceeds `MAX_PROBE_READS`.
+    fn require_probe_reads(&self, reads: U256) -> Result<(), Vec<u8>> {
+        let max = U256::from(MAX_PROBE_READS);
+        if reads > max {
+            return Err(ProbeTooLarge {
+                requested: reads,
+                max,
+            }
+            .abi_encode());
         }
+        Ok(())
+    }
+
+    /// Record a warm/cold probe run of `reads` storage reads ending in `hash`.
+    fn record_probe(&mut self, reads: U256, hash: [u8; 32]) -> B256 {
+        let final_hash = B256::from(hash);
+        let stats = RunStats {
+            reads,
+            ..RunStats::default()
+        };
+        self.record_run_with(reads, final_hash, ALG_KECCAK256, stats);
+        final_hash
+    }
+
+    /// Record a run with no extra writes, reads, or gas measurement; see
+    /// `record_run_with`.
+    fn record_run(&mut self, iterations: U256, final_hash: B256, algorithm: u8) {
+        self.record_run_with(iterations, final_hash, algorithm, RunStats::default());
+    }
+
+    /// Add the run to the caller's `calls_by_sender` entry and to
+    /// `total_iterations`, then finish it via `finish_run`.
+    fn record_run_with(
+        &mut self,
+        iterations: U256,
+        final_hash: B256,
+        algorithm: u8,
+        stats: RunStats,
+    ) {
+        let mut caller_count = self.calls_by_sender.setter(msg::sender());
+        let previous = caller_count.get();
+        caller_count.set(previous + U256::from(1));
+
+        let total = self.total_iterations.get();
+        self.total_iterations.set(total.saturating_add(iterations));
+
+        self.finish_run(iterations, final_hash, algorithm, stats);
+    }
 
-        // Single SSTORE: increment call counter
+    /// Increment `call_count`, store the result in `last_hash`, and emit
+    /// `ComputeCompleted` with the given per-run `stats`. These are the only
+    /// writes `compute_hash` makes.
+    fn finish_run(&mut self, iterations: U256, final_hash: B256, algorithm: u8, stats: RunStats) {
         let count = self.call_count.get();
         self.call_count.set(count + U256::from(1));
 
-        let final_hash = B256::from(hash);
+        self.last_hash.set(final_hash);
 
-        // Emit event
         evm::log(ComputeCompleted {
             iterations,
+            caller: msg::sender(),
             finalHash: final_hash,
+            algorithm,
+            writes: stats.writes,
+            reads: stats.reads,
+            gasUsed: stats.gas_used,
+            accumulator: stats.accumulator,
         });
+    }
+}
 
-        Ok(final_hash)
+/// Hash `seed`, then rehash the 32-byte result `iterations` times.
+///
+/// This is pure WASM computation priced in ink.
+fn keccak_chain(seed: &[u8], iterations: U256) -> B256 {
+    let hash: [u8; 32] = crypto::keccak(seed).into();
+    B256::from(keccak_rounds(hash, iterations.saturating_to::<u64>()))
+}
+
+/// Rehash a 32-byte value `rounds` times with keccak256.
+fn keccak_rounds(mut hash: [u8; 32], rounds: u64) -> [u8; 32] {
+    for _ in 0..rounds {
+        hash = crypto::keccak(hash).into();
     }
+    hash
+}
 
-    /// Get the total number of compute_hash calls.
-    pub fn call_count(&self) -> U256 {
-        self.call_count.get()
+/// Same chain as `keccak_chain`, using sha256 computed in WASM.
+fn sha256_chain(seed: &[u8], iterations: U256) -> B256 {
+    let mut hash: [u8; 32] = Sha256::digest(seed).into();
+
+    let n = iterations.saturating_to::<u64>();
+    for _ in 0..n {
+        hash = Sha256::digest(hash).into();
     }
+
+    B256::from(hash)
 }
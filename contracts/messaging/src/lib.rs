@@ -6,36 +6,354 @@
 //! content to L1 where it can be executed after the challenge period.
 
 #![cfg_attr(not(any(feature = "export-abi", test)), no_main)]
+// The generated ABI exporter chains one iterator per public item and needs
+// more than the default recursion limit for this contract's surface.
+#![cfg_attr(feature = "export-abi", recursion_limit = "256")]
 
 extern crate alloc;
 
 use alloc::string::String;
 use alloc::vec::Vec;
 use stylus_sdk::prelude::*;
-use stylus_sdk::storage::{StorageAddress, StorageMap, StorageString, StorageU256};
-use stylus_sdk::alloy_primitives::{Address, Bytes, U256};
-use stylus_sdk::{evm, msg};
-use alloy_sol_types::{sol, SolError};
+use stylus_sdk::storage::{
+    StorageAddress, StorageBool, StorageMap, StorageString, StorageU256, StorageVec,
+};
+use stylus_sdk::alloy_primitives::{Address, Bytes, FixedBytes, B256, U256};
+use stylus_sdk::{block, call, contract, crypto, evm, msg};
+use alloy_sol_types::{sol, SolError, SolValue};
 
 // ---------------------------------------------------------------------------
-// Events and errors — ABI-compatible with Solidity via the sol! macro
+// Structs, events, and errors — ABI-compatible with Solidity via the sol! macro
 // ---------------------------------------------------------------------------
 
 sol! {
-    /// Emitted when a new message is stored on-chain.
-    event MessageSent(uint256 indexed id, address indexed sender, string content);
+    /// A stored message with its ID and sender, returned by `get_message_full`.
+    #[derive(AbiType)]
+    struct Message {
+        uint256 id;
+        address sender;
+        string content;
+    }
+
+    /// Result of `lookup_message`: `found` is false only for IDs that were
+    /// never assigned.
+    #[derive(AbiType)]
+    struct MessageLookup {
+        bool found;
+        string content;
+    }
+
+    /// Result of `send_message_ex`: the assigned ID and the recorded sender.
+    #[derive(AbiType)]
+    struct SendResult {
+        uint256 id;
+        address sender;
+    }
+
+    /// Emitted when a new message is stored on-chain, while `emit_content` is
+    /// on (the default). Carries the full stored content.
+    event MessageSent(uint256 indexed id, address indexed sender, string content, uint256 timestamp);
+
+    /// Emitted instead of `MessageSent` while `emit_content` is off. Carries
+    /// no content or timestamp; read them with `get_message` and
+    /// `get_timestamp`.
+    event MessageSentLite(uint256 indexed id, address indexed sender);
+
+    /// Emitted when the owner switches between `MessageSent` and
+    /// `MessageSentLite`.
+    event EmitContentUpdated(bool enabled);
 
     /// Emitted when a message is submitted to the L2-to-L1 bridge.
-    event MessageBridged(uint256 indexed id, bytes32 bridgeTxHash);
+    /// `encoding` identifies the L1 payload format: 0 = raw UTF-8 content,
+    /// 1 = `abi.encode(id, sender, content)`, 2 = `selector ++ abi.encode(id,
+    /// content)`. `selector` is zero unless `encoding` is 2.
+    event MessageBridged(uint256 indexed id, address indexed destination, bytes32 bridgeTxHash, uint8 encoding, bytes4 selector);
+
+    /// Emitted when several messages are bridged as one L1 payload by
+    /// `bridge_combined`.
+    event CombinedBridged(uint256[] ids, address indexed destination, bytes32 bridgeTxHash);
+
+    /// Emitted when the sender of a message replaces its content.
+    event MessageEdited(uint256 indexed id, address indexed editor, string newContent);
+
+    /// Emitted when the sender of a message overwrites it with
+    /// `replace_message`, without recording edit history.
+    event MessageReplaced(uint256 indexed id, address indexed sender);
+
+    /// Emitted when the sender of a message retracts it.
+    event MessageDeleted(uint256 indexed id, address indexed sender);
+
+    /// Emitted when the owner pauses message submission and bridging.
+    event Paused(address account);
+
+    /// Emitted when the owner resumes message submission and bridging.
+    event Unpaused(address account);
+
+    /// Emitted once when the owner permanently freezes message submission.
+    event Frozen(address indexed by);
+
+    /// Emitted when `message_count` reaches a milestone: a configured value
+    /// from `set_milestones`, or by default a power of ten from 10 upwards.
+    event Milestone(uint256 count);
+
+    /// Emitted alongside `MessageSent` when an address posts for the first
+    /// time.
+    event FirstMessageFromSender(address indexed sender, uint256 indexed id);
+
+    /// Emitted when the owner changes the per-sender message quota.
+    event QuotaUpdated(uint256 max);
+
+    /// Emitted when a message send pays a non-zero fee.
+    event FeeCollected(uint256 id, uint256 amount);
+
+    /// Emitted when a message is stored as a reply to another message.
+    event ReplyCreated(uint256 indexed child, uint256 indexed parent);
+
+    /// Emitted when a message is handed to a new owner.
+    event MessageOwnershipTransferred(uint256 indexed id, address indexed from, address indexed to);
+
+    /// Emitted when a bridge call forwards a non-zero value to L1.
+    event BridgeValueForwarded(uint256 id, uint256 value);
+
+    /// Emitted when a message is filed under a non-zero tag.
+    event MessageTagged(uint256 indexed id, uint256 indexed tag);
+
+    /// Emitted when the owner adds or removes an address from the allowlist.
+    event AllowlistUpdated(address indexed addr, bool allowed);
+
+    /// Emitted when the owner bans or unbans an address.
+    event BlocklistUpdated(address indexed addr, bool blocked);
+
+    /// Emitted when the owner bans or unbans a content hash.
+    event ContentBanUpdated(uint256 indexed hash, bool banned);
+
+    /// Emitted when the owner nominates a new owner; `newOwner` must accept.
+    event OwnershipTransferStarted(address indexed previousOwner, address indexed newOwner);
+
+    /// Emitted when a pending owner accepts and becomes the owner.
+    event OwnershipTransferred(address indexed previousOwner, address indexed newOwner);
+
+    /// Emitted when `reader` acknowledges a message with `mark_read`.
+    event MessageRead(uint256 indexed id, address indexed reader);
+
+    /// Emitted when a message is read through the state-changing `read_message`.
+    event MessageAccessed(uint256 indexed id, address indexed reader);
+
+    /// Emitted when the owner changes the namespace prefix.
+    event PrefixUpdated(string prefix);
+
+    /// Emitted when the owner points bridging at a different ArbSys address.
+    event ArbSysUpdated(address arbsys);
+
+    /// Emitted when caller-supplied content is bridged by `bridge_raw`
+    /// without being stored.
+    event RawBridged(address indexed destination, bytes32 ticket);
+
+    /// Emitted when the owner adds or removes an L1 bridge destination.
+    event BridgeDestinationUpdated(address indexed destination, bool allowed);
+
+    /// Emitted when the owner turns the bridge destination whitelist on or off.
+    event BridgeRestrictedUpdated(bool restricted);
+
+    /// Emitted when a stored message is longer than `soft_limit` bytes. The
+    /// message is stored normally; this only flags it for moderation.
+    event MessageOverSoftLimit(uint256 indexed id, uint256 length);
+
+    /// Emitted when the owner pins a message.
+    event MessagePinned(uint256 indexed id);
+
+    /// Emitted when the owner unpins a message.
+    event MessageUnpinned(uint256 indexed id);
+
+    /// Emitted when `liker` likes a message.
+    event MessageLiked(uint256 indexed id, address indexed liker);
+
+    /// Emitted when an expired message's content is cleared by `prune`.
+    event MessagePruned(uint256 indexed id);
+
+    /// Emitted when the owner clears a message with `delete_and_refund`.
+    event MessageCleaned(uint256 indexed id, address indexed by, uint256 bounty);
+
+    /// Emitted by `send_message_keyed` with a caller-computed topic for log
+    /// filtering. The contract does not interpret `key`.
+    event MessageKeyed(uint256 indexed id, bytes32 indexed key);
+
+    /// Emitted by `send_and_bridge` when the message was stored but bridging
+    /// it failed. `reason` is the ABI-encoded error the bridge reverted with.
+    event BridgeAttemptFailed(uint256 indexed id, bytes reason);
+
+    /// Emitted when the owner toggles acceptance of empty messages.
+    event AllowEmptyUpdated(bool allowed);
+
+    /// Emitted when the owner turns whitespace-only rejection on or off.
+    event RejectWhitespaceUpdated(bool enabled);
+
+    /// Emitted when the owner turns content encoding checks on or off.
+    event ValidateUtf8Updated(bool enabled);
 
     /// The requested message ID does not exist.
     error MessageNotFound(uint256 id);
 
-    /// The bridge call to ArbSys failed.
-    error BridgeCallFailed(bytes reason);
+    /// The bridge call to ArbSys failed. `index` is the position of the
+    /// failing message within a `bridge_messages` batch (0 for single bridges);
+    /// `reason` is the raw revert data returned by ArbSys.
+    error BridgeCallFailed(uint256 index, bytes reason);
 
     /// The caller supplied an empty message string.
     error EmptyMessage();
+
+    /// The caller is not the recorded sender of the message.
+    error NotMessageOwner(uint256 id, address caller);
+
+    /// The requested message existed but has been deleted by its sender.
+    /// (Named apart from the `MessageDeleted` event, which Solidity requires.)
+    error MessageTombstoned(uint256 id);
+
+    /// The L1 bridge destination is the zero address.
+    error InvalidDestination();
+
+    /// The message has already been bridged to L1.
+    error AlreadyBridged(uint256 id);
+
+    /// A paginated read asked for more entries than a single call allows.
+    error PageTooLarge(uint256 requested, uint256 max);
+
+    /// The caller is not the contract owner.
+    error NotOwner(address caller);
+
+    /// `init` was called after the owner had already been set.
+    error AlreadyInitialized();
+
+    /// An address argument was the zero address.
+    error ZeroAddress();
+
+    /// The contract is paused; sends and bridges are disabled.
+    error ContractPaused();
+
+    /// The value sent with `send_message` is below the configured fee.
+    error InsufficientFee(uint256 required, uint256 provided);
+
+    /// A batch call supplied more entries than a single call allows.
+    error BatchTooLarge(uint256 requested, uint256 max);
+
+    /// The message content exceeds the maximum length in bytes.
+    error MessageTooLong(uint256 length, uint256 max);
+
+    /// The message is a root message, not a reply.
+    error NotAReply(uint256 id);
+
+    /// The sender posted too recently; retry at or after `nextAllowedBlock`.
+    error RateLimited(uint256 nextAllowedBlock);
+
+    /// `import_message` targeted an ID that already holds a message.
+    error IdAlreadyUsed(uint256 id);
+
+    /// `import_message` targeted `type(uint256).max`, which leaves no room for
+    /// `message_count` to point past it.
+    error IdOutOfRange(uint256 id);
+
+    /// A bridge call was re-entered while another was still in progress.
+    error Reentrancy();
+
+    /// The address has never posted a message.
+    error NoMessagesFromSender(address sender);
+
+    /// The signature does not recover to the claimed author.
+    error InvalidSignature(address author);
+
+    /// Allowlist mode is on and the sender is not approved to post.
+    error NotAllowlisted(address sender);
+
+    /// The caller (or relayed author) has been banned by the owner.
+    error SenderBlocked(address sender);
+
+    /// Only the pending owner may accept ownership.
+    error NotPendingOwner(address caller);
+
+    /// `pin_message` would exceed the pinned-message cap.
+    error TooManyPins(uint256 max);
+
+    /// The caller has already liked this message.
+    error AlreadyLiked(uint256 id, address liker);
+
+    /// The message's TTL has passed.
+    error MessageExpired(uint256 id);
+
+    /// `prune` was called on a message that has not expired.
+    error MessageNotExpired(uint256 id);
+
+    /// A display name exceeds `MAX_NAME_LENGTH` bytes.
+    error NameTooLong(uint256 length, uint256 max);
+
+    /// The caller is neither the author nor an authorized reader of a
+    /// private message.
+    error NotAuthorizedReader(uint256 id, address caller);
+
+    /// The contract has been permanently frozen; no new messages are accepted.
+    error ContractFrozen();
+
+    /// Content contains a control character at byte offset `position` while
+    /// `validate_utf8` is on.
+    error InvalidEncoding(uint256 position);
+
+    /// Bridge destination whitelisting is on and `destination` is not approved.
+    error DestinationNotAllowed(address destination);
+
+    /// No readable message exists yet.
+    error NoMessages();
+
+    /// `collected_fees` cannot cover the cleanup bounty.
+    error InsufficientBalance(uint256 available, uint256 required);
+
+    /// `set_milestones` was given more than `max` values.
+    error TooManyMilestones(uint256 max);
+
+    /// `set_milestones` values must be strictly ascending.
+    error MilestonesNotAscending();
+
+    /// The byte range `[offset, end)` would split a UTF-8 character.
+    error InvalidSlice(uint256 offset, uint256 end);
+
+    /// The content's keccak256 hash has been banned by the owner.
+    error ContentBanned(uint256 hash);
+
+    /// No bridge has been recorded under ArbSys ticket `ticket`.
+    error UnknownTicket(uint256 ticket);
+
+    /// The sender has already posted `used` messages and the quota is `max`.
+    error QuotaExceeded(uint256 used, uint256 max);
+}
+
+// `#[derive(AbiType)]` covers encoding, but `cargo stylus export-abi` also
+// needs each returned struct's Solidity definition.
+#[cfg(feature = "export-abi")]
+impl stylus_sdk::abi::export::internal::InnerTypes for Message {
+    fn inner_types() -> Vec<stylus_sdk::abi::export::internal::InnerType> {
+        alloc::vec![stylus_sdk::abi::export::internal::InnerType {
+            name: String::from("struct Message { uint256 id; address sender; string content; }"),
+            id: core::any::TypeId::of::<Message>(),
+        }]
+    }
+}
+
+#[cfg(feature = "export-abi")]
+impl stylus_sdk::abi::export::internal::InnerTypes for MessageLookup {
+    fn inner_types() -> Vec<stylus_sdk::abi::export::internal::InnerType> {
+        alloc::vec![stylus_sdk::abi::export::internal::InnerType {
+            name: String::from("struct MessageLookup { bool found; string content; }"),
+            id: core::any::TypeId::of::<MessageLookup>(),
+        }]
+    }
+}
+
+#[cfg(feature = "export-abi")]
+impl stylus_sdk::abi::export::internal::InnerTypes for SendResult {
+    fn inner_types() -> Vec<stylus_sdk::abi::export::internal::InnerType> {
+        alloc::vec![stylus_sdk::abi::export::internal::InnerType {
+            name: String::from("struct SendResult { uint256 id; address sender; }"),
+            id: core::any::TypeId::of::<SendResult>(),
+        }]
+    }
 }
 
 // ---------------------------------------------------------------------------
@@ -55,6 +373,53 @@ const ARBSYS_ADDR: Address = Address::new([
     0x00, 0x00, 0x00, 0x64,
 ]);
 
+/// ecrecover precompile, used to verify relayed signatures.
+const ECRECOVER_ADDR: Address = Address::with_last_byte(0x01);
+
+/// Contract version reported by `version()`. Bump the minor version for ABI
+/// additions and the major version for breaking ABI changes.
+const VERSION: &str = "4.1.0";
+
+/// Maximum number of entries returned by a single paginated read.
+const MAX_PAGE_SIZE: u64 = 100;
+
+/// Maximum number of entries accepted by a single batch call.
+const MAX_BATCH_SIZE: u64 = 50;
+
+/// Maximum display name length in bytes.
+const MAX_NAME_LENGTH: u64 = 64;
+
+/// Maximum number of pinned messages.
+const MAX_PINS: u64 = 16;
+
+/// Maximum number of values accepted by `set_milestones`.
+const MAX_MILESTONES: u64 = 32;
+
+/// Maximum message length in bytes while `max_length` is unset. Large enough
+/// for the 4 KB message-size load test scenario.
+const DEFAULT_MAX_LENGTH: u64 = 4096;
+
+/// Challenge period in L1 blocks while `challenge_period` is unset: Arbitrum
+/// One's 45,818 blocks, about 6.4 days.
+const DEFAULT_CHALLENGE_PERIOD: u64 = 45_818;
+
+/// Fixed storage per message counted by `estimated_storage_bytes`: the
+/// content root, content hash, sender, timestamp, and sender index slots.
+const MESSAGE_OVERHEAD_BYTES: u64 = 5 * 32;
+
+/// One exported message: `(id, sender, content, timestamp)`.
+type ExportEntry = (U256, Address, String, U256);
+
+/// `MessageBridged.encoding`: the L1 payload is the raw UTF-8 content.
+const ENCODING_RAW: u8 = 0;
+
+/// `MessageBridged.encoding`: the L1 payload is `abi.encode(id, sender, content)`.
+const ENCODING_ABI: u8 = 1;
+
+/// `MessageBridged.encoding`: the L1 payload is calldata,
+/// `selector ++ abi.encode(id, content)`.
+const ENCODING_CALL: u8 = 2;
+
 // ---------------------------------------------------------------------------
 // Contract storage
 // ---------------------------------------------------------------------------
@@ -65,12 +430,122 @@ const ARBSYS_ADDR: Address = Address::new([
 /// - `messages`:      message ID -> content string
 /// - `senders`:       message ID -> sender address
 /// - `message_count`: auto-incrementing message counter (next available ID)
+/// - `edit_history`:  message ID -> previous contents, oldest first
+/// - `deleted`:       message ID -> tombstone flag
+/// - `timestamps`:    message ID -> block timestamp at which it was stored
+/// - `messages_by_sender`: sender address -> IDs of messages it sent, in order
+/// - `bridge_tickets`: message ID -> ArbSys ticket ID of its bridge
+/// - `bridged`:       message ID -> whether it has been bridged successfully
+/// - `owner`:         admin address, set once via `init`
+/// - `paused`:        whether sends and bridges are currently disabled
+/// - `send_fee`:      wei required per `send_message` call
+/// - `max_length`:    maximum content length in bytes (0 = `DEFAULT_MAX_LENGTH`)
+/// - `reply_parent`:  message ID -> parent ID + 1 (0 = root message)
+/// - `min_block_gap`: minimum blocks between posts by one sender (0 = unlimited)
+/// - `last_post_block`: sender address -> block number of its last post
+/// - `locked`:        reentrancy guard held for the duration of a bridge call
+/// - `latest_by_sender`: sender address -> ID of its newest message + 1 (0 = none)
+/// - `tags`:          message ID -> tag (0 = untagged)
+/// - `messages_by_tag`: tag -> IDs of messages filed under it, in order (tag 0 is not indexed)
+/// - `nonces`:        author address -> number of signed messages it has had relayed
+/// - `content_hashes`: message ID -> keccak256 of its current content (0 once deleted)
+/// - `allowlist_enabled`: whether only `allowed` addresses may post
+/// - `allowed`:       address -> approved to post while allowlist mode is on
+/// - `blocked`:       address -> banned from posting, editing, and bridging
+/// - `pending_owner`: owner nominated by `transfer_ownership`, awaiting acceptance
+/// - `read_receipts`: `pair_key(id, reader)` -> whether `reader` has marked message `id` read
+/// - `prefix`:        namespace prepended to all content before storage (empty = none)
+/// - `collected_fees`: fees received and not yet withdrawn, in wei
+/// - `arbsys_addr`:   ArbSys override for bridging, e.g. a test mock (zero = `ARBSYS_ADDR`)
+/// - `block_counts`:  block number -> messages stored in that block
+/// - `soft_limit`:    length in bytes above which sends are flagged, not rejected (0 = off)
+/// - `pinned`:        owner-pinned message IDs, in pin order
+/// - `likes`:         message ID -> number of distinct likers
+/// - `liked`:         `pair_key(id, liker)` -> whether `liker` has liked message `id`
+/// - `expiry`:        message ID -> last block at which it is readable (0 = never expires)
+/// - `allow_empty`:   whether empty content is accepted instead of reverting
+/// - `display_names`: address -> self-registered display name (empty = unset)
+/// - `private_readers`: `pair_key(id, reader)` -> whether `reader` may `read_private` message `id`
+/// - `frozen`:        whether submission has been permanently closed by `freeze`
+/// - `validate_utf8`: whether sends reject content containing control characters
+/// - `bridge_allowed`: L1 address -> whether it may receive bridged messages while restricted
+/// - `bridge_restricted`: whether bridging is limited to `bridge_allowed` destinations
+/// - `seen_sender`:   address -> whether it has posted at least once
+/// - `unique_senders`: number of distinct addresses in `seen_sender`
+/// - `cleanup_bounty`: wei paid from `collected_fees` to the caller of `delete_and_refund`
+/// - `milestones`:    ascending `message_count` values that emit `Milestone` (empty = powers of ten)
+/// - `next_milestone`: index into `milestones` of the next value not yet reached
+/// - `reject_whitespace`: whether whitespace-only content counts as empty
+/// - `idempotent_ids`: `pair_key(nonce, sender)` -> message ID + 1 stored by `send_message_idempotent` (0 = unused)
+/// - `banned_hashes`: `content_hash` of stored content -> whether sends of it are rejected
+/// - `bridge_blocks`: ArbSys ticket ID -> block number at which it was bridged
+/// - `challenge_period`: L1 blocks from bridging to L1 executability (0 = `DEFAULT_CHALLENGE_PERIOD`)
+/// - `digest`:        rolling keccak256 commitment over every sent message (0 before the first)
+/// - `max_per_sender`: most messages one address may send (0 = unlimited)
+/// - `sent_counts`:   address -> messages sent, counted against `max_per_sender`
+/// - `total_content_bytes`: sum of current content lengths in bytes, for `estimated_storage_bytes`
+/// - `omit_content`:  whether sends emit `MessageSentLite` instead of `MessageSent` (inverse of `emit_content`)
 #[storage]
 #[entrypoint]
 pub struct MessagingContract {
     messages: StorageMap<U256, StorageString>,
     senders: StorageMap<U256, StorageAddress>,
     message_count: StorageU256,
+    edit_history: StorageMap<U256, StorageVec<StorageString>>,
+    deleted: StorageMap<U256, StorageBool>,
+    timestamps: StorageMap<U256, StorageU256>,
+    messages_by_sender: StorageMap<Address, StorageVec<StorageU256>>,
+    bridge_tickets: StorageMap<U256, StorageU256>,
+    bridged: StorageMap<U256, StorageBool>,
+    owner: StorageAddress,
+    paused: StorageBool,
+    send_fee: StorageU256,
+    max_length: StorageU256,
+    reply_parent: StorageMap<U256, StorageU256>,
+    min_block_gap: StorageU256,
+    last_post_block: StorageMap<Address, StorageU256>,
+    locked: StorageBool,
+    latest_by_sender: StorageMap<Address, StorageU256>,
+    tags: StorageMap<U256, StorageU256>,
+    messages_by_tag: StorageMap<U256, StorageVec<StorageU256>>,
+    nonces: StorageMap<Address, StorageU256>,
+    content_hashes: StorageMap<U256, StorageU256>,
+    allowlist_enabled: StorageBool,
+    allowed: StorageMap<Address, StorageBool>,
+    blocked: StorageMap<Address, StorageBool>,
+    pending_owner: StorageAddress,
+    read_receipts: StorageMap<B256, StorageBool>,
+    prefix: StorageString,
+    collected_fees: StorageU256,
+    arbsys_addr: StorageAddress,
+    block_counts: StorageMap<U256, StorageU256>,
+    soft_limit: StorageU256,
+    pinned: StorageVec<StorageU256>,
+    likes: StorageMap<U256, StorageU256>,
+    liked: StorageMap<B256, StorageBool>,
+    expiry: StorageMap<U256, StorageU256>,
+    allow_empty: StorageBool,
+    display_names: StorageMap<Address, StorageString>,
+    private_readers: StorageMap<B256, StorageBool>,
+    frozen: StorageBool,
+    validate_utf8: StorageBool,
+    bridge_allowed: StorageMap<Address, StorageBool>,
+    bridge_restricted: StorageBool,
+    seen_sender: StorageMap<Address, StorageBool>,
+    unique_senders: StorageU256,
+    cleanup_bounty: StorageU256,
+    milestones: StorageVec<StorageU256>,
+    next_milestone: StorageU256,
+    reject_whitespace: StorageBool,
+    idempotent_ids: StorageMap<B256, StorageU256>,
+    banned_hashes: StorageMap<U256, StorageBool>,
+    bridge_blocks: StorageMap<U256, StorageU256>,
+    challenge_period: StorageU256,
+    digest: StorageU256,
+    max_per_sender: StorageU256,
+    sent_counts: StorageMap<Address, StorageU256>,
+    total_content_bytes: StorageU256,
+    omit_content: StorageBool,
 }
 
 // ---------------------------------------------------------------------------
@@ -81,124 +556,2769 @@ pub struct MessagingContract {
 impl MessagingContract {
     /// Store a new message on-chain.
     ///
-    /// Assigns the next sequential ID, records the caller as sender, and emits
-    /// a `MessageSent` event. Returns the assigned message ID.
+    /// Assigns the next sequential ID, records the caller as sender and the
+    /// current block timestamp, and emits a `MessageSent` event. Returns the
+    /// assigned message ID. If a namespace `prefix` is set, it is prepended to
+    /// `content` before storage.
+    ///
+    /// Payable: the attached value must cover `send_fee`. Any excess is kept
+    /// with the fee and can be withdrawn by the owner.
     ///
     /// # Errors
-    /// Reverts with `EmptyMessage` if `content` is an empty string.
+    /// - `ContractPaused` if the owner has paused the contract.
+    /// - `ContractFrozen` if the owner has frozen the contract.
+    /// - `QuotaExceeded` if the caller has used up `max_per_sender`.
+    /// - `InsufficientFee` if `msg::value()` is below `send_fee`.
+    /// - `EmptyMessage` if `content` is empty and `allow_empty` is off.
+    /// - `MessageTooLong` if `content` exceeds `max_length` bytes once prefixed.
+    /// - `RateLimited` if the caller posted within the last `min_block_gap` blocks.
+    /// - `NotAllowlisted` if allowlist mode is on and the caller is not approved.
+    /// - `SenderBlocked` if the caller has been banned.
+    #[payable]
     pub fn send_message(&mut self, content: String) -> Result<U256, Vec<u8>> {
-        if content.is_empty() {
-            return Err(EmptyMessage {}.abi_encode());
+        self.send_from_caller(content)
+    }
+
+    /// Store a message signed off-chain by `author`, submitted by a relayer.
+    ///
+    /// `signature` is a 65-byte `r || s || v` EIP-191 (`personal_sign`)
+    /// signature over `signed_message_digest(author, content)`, which commits
+    /// to this contract's address and the author's current nonce. `author` is
+    /// recorded as the sender and the nonce is bumped, so each signature can
+    /// be used once. The relayer pays the fee; rate limits apply to `author`.
+    ///
+    /// # Errors
+    /// - `ZeroAddress` if `author` is the zero address.
+    /// - `InvalidSignature` if the signature does not recover to `author`.
+    /// - Any error `send_message` can return.
+    #[payable]
+    pub fn send_message_signed(
+        &mut self,
+        content: String,
+        author: Address,
+        signature: stylus_sdk::abi::Bytes,
+    ) -> Result<U256, Vec<u8>> {
+        if author.is_zero() {
+            return Err(ZeroAddress {}.abi_encode());
+        }
+        let digest = self.signed_message_digest(author, content.clone());
+        if recover_signer(digest, &signature) != Some(author) {
+            return Err(InvalidSignature { author }.abi_encode());
         }
 
-        // Allocate the next ID and advance the counter.
-        let id = self.message_count.get();
-        self.message_count.set(id + U256::from(1));
+        let nonce = self.nonces.get(author);
+        self.nonces.setter(author).set(nonce + U256::from(1));
 
-        // Persist message content and sender address.
-        self.messages.setter(id).set_str(&content);
-        self.senders.setter(id).set(msg::sender());
+        self.send_as(author, content)
+    }
 
-        // Emit Solidity-compatible event for indexers.
-        evm::log(MessageSent {
-            id,
-            sender: msg::sender(),
-            content,
-        });
+    /// Get the nonce that `author`'s next signed message must commit to.
+    pub fn nonce_of(&self, author: Address) -> U256 {
+        self.nonces.get(author)
+    }
 
-        Ok(id)
+    /// Get the 32-byte digest `author` must sign (with the EIP-191 prefix)
+    /// for `send_message_signed`.
+    ///
+    /// `keccak256(abi.encodePacked(address(this), author, nonce, content))`,
+    /// where `nonce` is `nonce_of(author)`.
+    pub fn signed_message_digest(&self, author: Address, content: String) -> B256 {
+        let mut packed = Vec::with_capacity(20 + 20 + 32 + content.len());
+        packed.extend_from_slice(contract::address().as_slice());
+        packed.extend_from_slice(author.as_slice());
+        packed.extend_from_slice(&self.nonces.get(author).to_be_bytes::<32>());
+        packed.extend_from_slice(content.as_bytes());
+        crypto::keccak(packed)
     }
 
-    /// Retrieve a message by its ID.
+    /// Store a new message that expires `ttl_blocks` blocks from now.
+    ///
+    /// Once `block::number()` passes the expiry block, `get_message` and the
+    /// other live-message paths revert with `MessageExpired`, although the
+    /// content stays in storage until someone calls `prune`. A `ttl_blocks`
+    /// of 0 stores a non-expiring message, exactly like `send_message`.
     ///
     /// # Errors
-    /// Reverts with `MessageNotFound` if the ID has not been assigned yet.
-    pub fn get_message(&self, id: U256) -> Result<String, Vec<u8>> {
-        if id >= self.message_count.get() {
-            return Err(MessageNotFound { id }.abi_encode());
+    /// Same as `send_message`.
+    #[payable]
+    pub fn send_message_expiring(
+        &mut self,
+        content: String,
+        ttl_blocks: U256,
+    ) -> Result<U256, Vec<u8>> {
+        let id = self.send_from_caller(content)?;
+        if !ttl_blocks.is_zero() {
+            let expires_at = U256::from(block::number()).saturating_add(ttl_blocks);
+            self.expiry.setter(id).set(expires_at);
         }
-        Ok(self.messages.getter(id).get_string())
+        Ok(id)
     }
 
-    /// Get the address that sent a given message.
+    /// Get the last block at which a message is readable (0 = never expires).
     ///
     /// # Errors
     /// Reverts with `MessageNotFound` if the ID has not been assigned yet.
-    pub fn get_sender(&self, id: U256) -> Result<Address, Vec<u8>> {
-        if id >= self.message_count.get() {
-            return Err(MessageNotFound { id }.abi_encode());
+    pub fn get_expiry(&self, id: U256) -> Result<U256, Vec<u8>> {
+        self.require_exists(id)?;
+        Ok(self.expiry.get(id))
+    }
+
+    /// Clear the content and edit history of an expired message to reclaim
+    /// storage, and emit `MessagePruned`. Anyone may call this.
+    ///
+    /// # Errors
+    /// - `MessageNotFound` if the ID has not been assigned yet.
+    /// - `MessageNotExpired` if the message has no expiry or has not passed it.
+    pub fn prune(&mut self, id: U256) -> Result<(), Vec<u8>> {
+        self.require_exists(id)?;
+        if !self.is_expired(id) {
+            return Err(MessageNotExpired { id }.abi_encode());
         }
-        Ok(self.senders.getter(id).get())
+
+        self.write_content(id, "");
+        self.edit_history.setter(id).erase();
+        self.content_hashes.setter(id).set(U256::ZERO);
+        evm::log(MessagePruned { id });
+        Ok(())
     }
 
-    /// Get the total number of messages stored.
+    /// Get the bounty in wei paid per `delete_and_refund` call.
+    pub fn cleanup_bounty(&self) -> U256 {
+        self.cleanup_bounty.get()
+    }
+
+    /// Set the bounty in wei paid per `delete_and_refund` call.
     ///
-    /// The returned value is also the next ID that will be assigned.
-    pub fn message_count(&self) -> U256 {
-        self.message_count.get()
+    /// # Errors
+    /// Reverts with `NotOwner` if the caller is not the owner.
+    pub fn set_cleanup_bounty(&mut self, amount: U256) -> Result<(), Vec<u8>> {
+        self.require_owner()?;
+        self.cleanup_bounty.set(amount);
+        Ok(())
     }
 
-    /// Bridge a stored message to L1 via the ArbSys precompile.
+    /// Tombstone a message, clearing its content and edit history to reclaim
+    /// storage, and pay `cleanup_bounty` to the caller. Emits
+    /// `MessageCleaned`.
     ///
-    /// Calls `ArbSys.sendTxToL1()` which enqueues an L2-to-L1 message that
-    /// becomes executable on L1 after the challenge period elapses.
+    /// The bounty is taken out of `collected_fees`, so it never spends value
+    /// the contract does not account for.
+    ///
+    /// # Errors
+    /// - `NotOwner` if the caller is not the owner.
+    /// - `MessageNotFound` if the ID has not been assigned yet.
+    /// - `MessageTombstoned` if the message was already deleted.
+    /// - `InsufficientBalance` if `collected_fees` is below the bounty.
+    /// - Whatever the caller reverts with if it rejects the transfer.
+    #[allow(deprecated)]
+    pub fn delete_and_refund(&mut self, id: U256) -> Result<(), Vec<u8>> {
+        self.require_owner()?;
+        self.require_exists(id)?;
+        if self.deleted.get(id) {
+            return Err(MessageTombstoned { id }.abi_encode());
+        }
+        let bounty = self.cleanup_bounty.get();
+        let available = self.collected_fees.get();
+        if available < bounty {
+            return Err(InsufficientBalance {
+                available,
+                required: bounty,
+            }
+            .abi_encode());
+        }
+
+        self.deleted.setter(id).set(true);
+        self.write_content(id, "");
+        self.edit_history.setter(id).erase();
+        self.content_hashes.setter(id).set(U256::ZERO);
+        self.collected_fees.set(available - bounty);
+
+        let caller = msg::sender();
+        evm::log(MessageCleaned {
+            id,
+            by: caller,
+            bounty,
+        });
+        if !bounty.is_zero() {
+            call::transfer_eth(caller, bounty)?;
+        }
+        Ok(())
+    }
+
+    /// Store a new message and emit `MessageKeyed` with `key` as an indexed
+    /// topic, e.g. a hashed hashtag.
     ///
-    /// The L1 destination address is set to `msg::sender()`, so the caller
-    /// will be the recipient on L1.
+    /// Nothing extra is stored; the key only exists in the log, so topic feeds
+    /// can be built with `eth_getLogs` filters.
     ///
-    /// # Safety
-    /// Uses the deprecated `StorageCache::flush()` before the cross-contract call,
-    /// which is required by the Stylus SDK to prevent storage aliasing during
-    /// reentrant or cross-contract execution.
+    /// # Errors
+    /// Same as `send_message`.
+    #[payable]
+    pub fn send_message_keyed(&mut self, content: String, key: B256) -> Result<U256, Vec<u8>> {
+        let id = self.send_from_caller(content)?;
+        evm::log(MessageKeyed { id, key });
+        Ok(id)
+    }
+
+    /// Store a new message at most once per `(caller, nonce)`.
+    ///
+    /// The first call with a given nonce behaves like `send_message`. Later
+    /// calls from the same sender with that nonce return the ID assigned the
+    /// first time without storing anything or emitting `MessageSent`, so a
+    /// relayer can safely retry. Value attached to a repeat is refunded.
     ///
     /// # Errors
-    /// - `MessageNotFound` if the ID does not exist.
-    /// - `BridgeCallFailed` if the ArbSys call reverts.
+    /// Same as `send_message` on a fresh nonce; a repeat only fails if the
+    /// caller rejects the refund.
+    #[payable]
     #[allow(deprecated)]
-    pub fn bridge_message(&mut self, id: U256) -> Result<(), Vec<u8>> {
-        // Verify the message exists before doing any external work.
-        if id >= self.message_count.get() {
-            return Err(MessageNotFound { id }.abi_encode());
+    pub fn send_message_idempotent(
+        &mut self,
+        content: String,
+        nonce: U256,
+    ) -> Result<U256, Vec<u8>> {
+        let sender = msg::sender();
+        let key = pair_key(nonce, sender);
+        let existing = self.idempotent_ids.get(key);
+        if !existing.is_zero() {
+            let refund = msg::value();
+            if !refund.is_zero() {
+                call::transfer_eth(sender, refund)?;
+            }
+            return Ok(existing - U256::from(1));
         }
 
-        // Read message content while we still hold the storage cache.
-        let content = self.messages.getter(id).get_string();
+        let id = self.send_as(sender, content)?;
+        self.idempotent_ids.setter(key).set(id + U256::from(1));
+        Ok(id)
+    }
 
-        // CRITICAL: flush the storage cache before any cross-contract call.
-        // The Stylus SDK requires this to ensure storage writes are committed
-        // before control transfers to another contract.
-        unsafe {
-            stylus_sdk::storage::StorageCache::flush();
+    /// Store a new message readable through `read_private` only by its author
+    /// and the given `readers`.
+    ///
+    /// This is contract-level access control only: the content is still
+    /// public chain data, visible in the `MessageSent` log, in storage, and
+    /// through the other getters. It exists for integrating contracts that
+    /// want an on-chain authorization check.
+    ///
+    /// # Errors
+    /// - `BatchTooLarge` if more than `MAX_BATCH_SIZE` readers are given.
+    /// - Any error `send_message` can return.
+    #[payable]
+    pub fn send_private_message(
+        &mut self,
+        content: String,
+        readers: Vec<Address>,
+    ) -> Result<U256, Vec<u8>> {
+        self.require_batch_size(readers.len())?;
+        let id = self.send_from_caller(content)?;
+        for reader in readers {
+            self.private_readers.setter(pair_key(id, reader)).set(true);
+        }
+        Ok(id)
+    }
+
+    /// Retrieve a private message if the caller is its author or one of its
+    /// authorized readers. See `send_private_message` for what this does and
+    /// does not protect.
+    ///
+    /// # Errors
+    /// - `NotAuthorizedReader` if the caller may not read the message.
+    /// - Any error `get_message` can return.
+    pub fn read_private(&mut self, id: U256) -> Result<String, Vec<u8>> {
+        self.require_live(id)?;
+        let caller = msg::sender();
+        if self.senders.get(id) != caller && !self.private_readers.get(pair_key(id, caller)) {
+            return Err(NotAuthorizedReader { id, caller }.abi_encode());
         }
+        Ok(self.messages.getter(id).get_string())
+    }
 
-        // Encode message content as raw bytes for L1 delivery.
-        let data: Bytes = content.as_bytes().to_vec().into();
+    /// Store a new message filed under channel `tag`.
+    ///
+    /// Behaves like `send_message`, then records the tag, appends the ID to
+    /// the tag's index, and emits `MessageTagged`. Tag 0 means untagged and is
+    /// not indexed, so `send_message_tagged(content, 0)` costs the same as
+    /// `send_message`.
+    ///
+    /// # Errors
+    /// Same as `send_message`.
+    #[payable]
+    pub fn send_message_tagged(&mut self, content: String, tag: U256) -> Result<U256, Vec<u8>> {
+        let id = self.send_from_caller(content)?;
+        if !tag.is_zero() {
+            self.tags.setter(id).set(tag);
+            self.messages_by_tag.setter(tag).push(id);
+            evm::log(MessageTagged { id, tag });
+        }
+        Ok(id)
+    }
 
-        // Invoke ArbSys.sendTxToL1(destination, data).
-        // destination = msg::sender() so the bridge message is addressed to the
-        // same account on L1 that initiated the bridge on L2.
-        let arbsys = IArbSys::new(ARBSYS_ADDR);
-        let config = stylus_sdk::call::Call::new();
+    /// Store a new message like `send_message`, returning both the assigned ID
+    /// and the recorded sender so relayers can confirm them without a
+    /// follow-up `get_sender` call.
+    ///
+    /// # Errors
+    /// Same as `send_message`.
+    #[payable]
+    pub fn send_message_ex(&mut self, content: String) -> Result<SendResult, Vec<u8>> {
+        let id = self.send_from_caller(content)?;
+        Ok(SendResult {
+            id,
+            sender: self.senders.get(id),
+        })
+    }
 
-        match arbsys.send_tx_to_l_1(config, msg::sender(), data) {
-            Ok(ticket_id) => {
-                // Convert the returned ticket ID (U256) to a bytes32 for the event.
-                let bridge_tx_hash: [u8; 32] = ticket_id.to_be_bytes();
+    /// Store a new message as a reply to `parent_id`.
+    ///
+    /// Behaves exactly like `send_message` (same fee and validation rules),
+    /// and additionally records the parent and emits `ReplyCreated`.
+    ///
+    /// # Errors
+    /// - `MessageNotFound` if the parent ID has not been assigned yet.
+    /// - `MessageTombstoned` if the parent was deleted.
+    /// - Any error `send_message` can return.
+    #[payable]
+    pub fn reply_to(&mut self, parent_id: U256, content: String) -> Result<U256, Vec<u8>> {
+        self.require_live(parent_id)?;
+        let id = self.send_from_caller(content)?;
 
-                evm::log(MessageBridged {
-                    id,
-                    bridgeTxHash: bridge_tx_hash.into(),
-                });
+        self.reply_parent.setter(id).set(parent_id + U256::from(1));
+        evm::log(ReplyCreated {
+            child: id,
+            parent: parent_id,
+        });
 
-                Ok(())
-            }
-            Err(err) => {
-                let reason: Vec<u8> = alloc::format!("{:?}", err).into_bytes();
-                Err(BridgeCallFailed {
-                    reason: reason.into(),
-                }
-                .abi_encode())
-            }
+        Ok(id)
+    }
+
+    /// Get the parent of a reply.
+    ///
+    /// # Errors
+    /// - `MessageNotFound` if the ID has not been assigned yet.
+    /// - `NotAReply` if the message is a root message.
+    pub fn get_parent(&self, id: U256) -> Result<U256, Vec<u8>> {
+        self.require_exists(id)?;
+        let stored = self.reply_parent.get(id);
+        if stored.is_zero() {
+            return Err(NotAReply { id }.abi_encode());
         }
+        Ok(stored - U256::from(1))
     }
-}
+
+    /// Store several messages in one transaction.
+    ///
+    /// Messages receive consecutive IDs in input order and each emits its own
+    /// `MessageSent` event. Every entry is validated before anything is
+    /// stored, so a bad entry reverts the whole batch without leaving gaps.
+    ///
+    /// Payable: the attached value must cover `send_fee` for every message.
+    /// A single `FeeCollected` event is emitted against the first ID.
+    ///
+    /// # Errors
+    /// - `BatchTooLarge` if more than `MAX_BATCH_SIZE` messages are supplied.
+    /// - `ContractPaused` if the owner has paused the contract.
+    /// - `ContractFrozen` if the owner has frozen the contract.
+    /// - `QuotaExceeded` if the caller has used up `max_per_sender`.
+    /// - `InsufficientFee` if `msg::value()` does not cover all messages.
+    /// - `EmptyMessage` if any entry is an empty string.
+    /// - `MessageTooLong` if any entry exceeds `max_length` bytes once prefixed.
+    /// - `RateLimited` if the caller posted within the last `min_block_gap`
+    ///   blocks. A batch counts as a single post.
+    #[payable]
+    pub fn send_messages(&mut self, contents: Vec<String>) -> Result<Vec<U256>, Vec<u8>> {
+        self.require_batch_size(contents.len())?;
+        self.require_not_paused()?;
+        self.require_not_frozen()?;
+        let sender = msg::sender();
+        self.require_allowed(sender)?;
+        self.require_not_blocked(sender)?;
+        self.require_quota(sender, contents.len())?;
+        let paid = self.require_fee(contents.len())?;
+        let contents = contents
+            .into_iter()
+            .map(|content| self.prepare_content(content))
+            .collect::<Result<Vec<_>, _>>()?;
+
+        self.apply_rate_limit(sender)?;
+        let ids: Vec<U256> = contents
+            .into_iter()
+            .map(|(content, hash)| self.store_message(sender, content, hash))
+            .collect();
+
+        if let Some(&id) = ids.first() {
+            self.record_fee(id, paid);
+        }
+
+        Ok(ids)
+    }
+
+    /// Retrieve a message by its ID.
+    ///
+    /// # Errors
+    /// - `MessageNotFound` if the ID has not been assigned yet.
+    /// - `MessageTombstoned` if the message was deleted by its sender.
+    /// - `MessageExpired` if the message's TTL has passed.
+    pub fn get_message(&self, id: U256) -> Result<String, Vec<u8>> {
+        self.require_live(id)?;
+        Ok(self.messages.getter(id).get_string())
+    }
+
+    /// Retrieve up to `len` bytes of a message's content starting at byte
+    /// `offset`, for fetching large messages in chunks.
+    ///
+    /// The range is clamped at the end of the content, so an `offset` at or
+    /// past it returns an empty string.
+    ///
+    /// # Errors
+    /// - `InvalidSlice` if either end of the range falls inside a multi-byte
+    ///   UTF-8 character.
+    /// - Same as `get_message` otherwise.
+    pub fn get_message_slice(&self, id: U256, offset: U256, len: U256) -> Result<String, Vec<u8>> {
+        self.require_live(id)?;
+        let content = self.messages.getter(id).get_string();
+        let total = U256::from(content.len());
+        let start = offset.min(total);
+        let end = offset.saturating_add(len).min(total);
+        match content.get(start.to::<usize>()..end.to::<usize>()) {
+            Some(slice) => Ok(String::from(slice)),
+            None => Err(InvalidSlice { offset, end }.abi_encode()),
+        }
+    }
+
+    /// Get the length in bytes of a message's content, for use with
+    /// `get_message_slice`.
+    ///
+    /// # Errors
+    /// Same as `get_message`.
+    pub fn get_message_length(&self, id: U256) -> Result<U256, Vec<u8>> {
+        self.require_live(id)?;
+        Ok(U256::from(self.messages.getter(id).len()))
+    }
+
+    /// Get the stored byte length of a message's content, reading only the
+    /// string's length slot rather than the content itself.
+    ///
+    /// Unlike `get_message_length` this does not revert for deleted or
+    /// expired messages; deleted and pruned messages report 0.
+    ///
+    /// # Errors
+    /// Reverts with `MessageNotFound` if the ID has not been assigned yet.
+    pub fn message_byte_length(&self, id: U256) -> Result<U256, Vec<u8>> {
+        self.require_exists(id)?;
+        Ok(U256::from(self.messages.getter(id).len()))
+    }
+
+    /// Retrieve a message like `get_message`, and also emit `MessageAccessed`
+    /// so on-chain consumers leave an auditable access trail.
+    ///
+    /// Off-chain readers should keep using the free `get_message`.
+    ///
+    /// # Errors
+    /// Same as `get_message`.
+    pub fn read_message(&mut self, id: U256) -> Result<String, Vec<u8>> {
+        let content = self.get_message(id)?;
+        evm::log(MessageAccessed {
+            id,
+            reader: msg::sender(),
+        });
+        Ok(content)
+    }
+
+    /// Get the address that sent a given message.
+    ///
+    /// # Errors
+    /// Reverts with `MessageNotFound` if the ID has not been assigned yet.
+    pub fn get_sender(&self, id: U256) -> Result<Address, Vec<u8>> {
+        self.require_exists(id)?;
+        Ok(self.senders.getter(id).get())
+    }
+
+    /// Get the sender of each message in `ids`, in the same order.
+    ///
+    /// Deleted messages still report their original sender, as `get_sender`
+    /// does.
+    ///
+    /// # Errors
+    /// - `BatchTooLarge` if more than `MAX_BATCH_SIZE` IDs are given.
+    /// - `MessageNotFound` if any ID has not been assigned yet.
+    pub fn get_senders(&self, ids: Vec<U256>) -> Result<Vec<Address>, Vec<u8>> {
+        self.require_batch_size(ids.len())?;
+        ids.into_iter().map(|id| self.get_sender(id)).collect()
+    }
+
+    /// Whether `addr` is the recorded sender of message `id`, i.e. whether
+    /// it may edit, replace, delete, or transfer it. Uses the same check as
+    /// those calls.
+    ///
+    /// # Errors
+    /// Reverts with `MessageNotFound` if the ID has not been assigned yet.
+    pub fn is_owner_of(&self, id: U256, addr: Address) -> Result<bool, Vec<u8>> {
+        self.require_exists(id)?;
+        Ok(self.owns_message(id, addr))
+    }
+
+    /// Look up a message without ever reverting.
+    ///
+    /// `found` is false if the ID was never assigned. A found message with
+    /// empty content is either a stored empty message or a deleted or
+    /// expired one; `exists` tells those apart from an empty message.
+    pub fn lookup_message(&self, id: U256) -> MessageLookup {
+        // Every stored message has a non-zero sender, so that marks occupancy.
+        let found = !self.senders.get(id).is_zero();
+        let content = if found {
+            self.visible_content(id)
+        } else {
+            String::new()
+        };
+        MessageLookup { found, content }
+    }
+
+    /// Retrieve a message together with its ID and sender.
+    ///
+    /// Equivalent to calling `get_message` and `get_sender` in a single
+    /// round-trip; read the timestamp with `get_timestamp`.
+    ///
+    /// # Errors
+    /// - `MessageNotFound` if the ID has not been assigned yet.
+    /// - `MessageTombstoned` if the message was deleted by its sender.
+    pub fn get_message_full(&self, id: U256) -> Result<Message, Vec<u8>> {
+        self.require_live(id)?;
+        Ok(Message {
+            id,
+            sender: self.senders.get(id),
+            content: self.messages.getter(id).get_string(),
+        })
+    }
+
+    /// Retrieve a message's content and sender together with the sender's
+    /// current display name (empty if unset).
+    ///
+    /// # Errors
+    /// Same as `get_message`.
+    pub fn get_message_with_name(&self, id: U256) -> Result<(String, Address, String), Vec<u8>> {
+        self.require_live(id)?;
+        let sender = self.senders.get(id);
+        Ok((
+            self.messages.getter(id).get_string(),
+            sender,
+            self.display_names.getter(sender).get_string(),
+        ))
+    }
+
+    /// Set the caller's display name. An empty string clears it.
+    ///
+    /// # Errors
+    /// Reverts with `NameTooLong` if `name` exceeds `MAX_NAME_LENGTH` bytes.
+    pub fn set_display_name(&mut self, name: String) -> Result<(), Vec<u8>> {
+        let length = U256::from(name.len());
+        let max = U256::from(MAX_NAME_LENGTH);
+        if length > max {
+            return Err(NameTooLong { length, max }.abi_encode());
+        }
+        self.display_names.setter(msg::sender()).set_str(&name);
+        Ok(())
+    }
+
+    /// Get the display name registered by `addr` (empty if unset).
+    pub fn get_display_name(&self, addr: Address) -> String {
+        self.display_names.getter(addr).get_string()
+    }
+
+    /// Retrieve up to `count` messages starting at ID `start`.
+    ///
+    /// Each entry is `(id, sender, content)`. The range is clamped at
+    /// `message_count`, so a page running past the newest message simply
+    /// returns fewer entries. Deleted and expired messages are included with
+    /// empty content.
+    ///
+    /// # Errors
+    /// Reverts with `PageTooLarge` if `count` exceeds `MAX_PAGE_SIZE`.
+    pub fn get_messages(
+        &self,
+        start: U256,
+        count: U256,
+    ) -> Result<Vec<(U256, Address, String)>, Vec<u8>> {
+        let max = U256::from(MAX_PAGE_SIZE);
+        if count > max {
+            return Err(PageTooLarge {
+                requested: count,
+                max,
+            }
+            .abi_encode());
+        }
+
+        let end = start.saturating_add(count).min(self.message_count.get());
+        let mut page = Vec::new();
+        let mut id = start;
+        while id < end {
+            page.push((id, self.senders.get(id), self.visible_content(id)));
+            id += U256::from(1);
+        }
+        Ok(page)
+    }
+
+    /// Retrieve the newest `count` messages, oldest first.
+    ///
+    /// Equivalent to `get_messages(message_count - count, count)`, with the
+    /// start clamped at zero when fewer than `count` messages exist. Deleted
+    /// and expired messages are included with empty content.
+    ///
+    /// # Errors
+    /// Reverts with `PageTooLarge` if `count` exceeds `MAX_PAGE_SIZE`.
+    pub fn get_recent(&self, count: U256) -> Result<Vec<(U256, Address, String)>, Vec<u8>> {
+        let start = self.message_count.get().saturating_sub(count);
+        self.get_messages(start, count)
+    }
+
+    /// Retrieve the newest readable message as `(id, sender, content)`.
+    ///
+    /// Walks back from `message_count - 1` past deleted and expired
+    /// messages, so the cost grows with the number of trailing tombstones.
+    ///
+    /// # Errors
+    /// Reverts with `NoMessages` if no message has been sent, or every
+    /// message has been deleted or has expired.
+    pub fn get_latest(&self) -> Result<(U256, Address, String), Vec<u8>> {
+        let mut id = self.message_count.get();
+        while !id.is_zero() {
+            id -= U256::from(1);
+            if self.require_live(id).is_ok() {
+                return Ok((
+                    id,
+                    self.senders.getter(id).get(),
+                    self.messages.getter(id).get_string(),
+                ));
+            }
+        }
+        Err(NoMessages {}.abi_encode())
+    }
+
+    /// Get the IDs of all messages sent by `sender`, oldest first.
+    ///
+    /// Deleted messages keep their entry; `get_message` reports them as
+    /// tombstoned.
+    pub fn get_sender_message_ids(&self, sender: Address) -> Vec<U256> {
+        let ids = self.messages_by_sender.getter(sender);
+        (0..ids.len()).filter_map(|i| ids.get(i)).collect()
+    }
+
+    /// Get the number of messages sent by `sender`.
+    pub fn get_sender_message_count(&self, sender: Address) -> U256 {
+        U256::from(self.messages_by_sender.getter(sender).len())
+    }
+
+    /// Get up to `count` of `sender`'s messages as `(id, content)`, starting
+    /// at position `start` in their index (oldest first).
+    ///
+    /// The page is clamped to the end of the index, so a page running past
+    /// the sender's newest message returns fewer entries rather than
+    /// reverting. Deleted and expired messages are included with empty
+    /// content.
+    ///
+    /// # Errors
+    /// Reverts with `PageTooLarge` if `count` exceeds `MAX_PAGE_SIZE`.
+    pub fn get_messages_from(
+        &self,
+        sender: Address,
+        start: U256,
+        count: U256,
+    ) -> Result<Vec<(U256, String)>, Vec<u8>> {
+        let max = U256::from(MAX_PAGE_SIZE);
+        if count > max {
+            return Err(PageTooLarge {
+                requested: count,
+                max,
+            }
+            .abi_encode());
+        }
+
+        let ids = self.messages_by_sender.getter(sender);
+        let end = start.saturating_add(count).min(U256::from(ids.len()));
+        let mut page = Vec::new();
+        let mut index = start;
+        while index < end {
+            if let Some(id) = ids.get(index) {
+                page.push((id, self.visible_content(id)));
+            }
+            index += U256::from(1);
+        }
+        Ok(page)
+    }
+
+    /// Get the ID of the most recent message sent by `sender`.
+    ///
+    /// Reads a single slot. Deleted messages still count; `get_message`
+    /// reports them as tombstoned.
+    ///
+    /// # Errors
+    /// Reverts with `NoMessagesFromSender` if the address never posted.
+    pub fn get_latest_message_id(&self, sender: Address) -> Result<U256, Vec<u8>> {
+        let latest = self.latest_by_sender.get(sender);
+        if latest.is_zero() {
+            return Err(NoMessagesFromSender { sender }.abi_encode());
+        }
+        Ok(latest - U256::from(1))
+    }
+
+    /// Get the keccak256 hash of a message's current content.
+    ///
+    /// Lets a client check cached content against the chain with one SLOAD
+    /// instead of reading the whole string. Returns 0 for deleted messages.
+    ///
+    /// # Errors
+    /// Reverts with `MessageNotFound` if the ID has not been assigned yet.
+    pub fn get_content_hash(&self, id: U256) -> Result<U256, Vec<u8>> {
+        self.require_exists(id)?;
+        Ok(self.content_hashes.get(id))
+    }
+
+    /// Get a message's sender and content hash in one call.
+    ///
+    /// Lets a light client confirm a claimed `(id, sender, body)` against a
+    /// locally cached body without fetching the string. Messages stored
+    /// before content hashes were recorded fall back to hashing the stored
+    /// content on the fly. Deleted and pruned messages report a hash of 0,
+    /// as `get_content_hash` does.
+    ///
+    /// # Errors
+    /// Reverts with `MessageNotFound` if the ID has not been assigned yet.
+    pub fn get_proof_data(&self, id: U256) -> Result<(Address, U256), Vec<u8>> {
+        self.require_exists(id)?;
+        let sender = self.senders.getter(id).get();
+        let mut hash = self.content_hashes.get(id);
+        if hash.is_zero() && !self.deleted.get(id) {
+            // Pruning empties the content, so only hash content that is left.
+            let content = self.messages.getter(id);
+            if !content.is_empty() {
+                hash = content_hash(&content.get_string());
+            }
+        }
+        Ok((sender, hash))
+    }
+
+    /// Record that the caller has read message `id` and emit `MessageRead`.
+    ///
+    /// Marking a message read again is allowed and re-emits the event.
+    ///
+    /// # Errors
+    /// Reverts with `MessageNotFound` if the ID has not been assigned yet.
+    pub fn mark_read(&mut self, id: U256) -> Result<(), Vec<u8>> {
+        self.require_exists(id)?;
+        let reader = msg::sender();
+        self.read_receipts.setter(pair_key(id, reader)).set(true);
+        evm::log(MessageRead { id, reader });
+        Ok(())
+    }
+
+    /// Whether `reader` has marked message `id` read.
+    pub fn has_read(&self, id: U256, reader: Address) -> bool {
+        self.read_receipts.get(pair_key(id, reader))
+    }
+
+    /// Like message `id` as the caller and emit `MessageLiked`.
+    ///
+    /// # Errors
+    /// - `MessageNotFound` if the ID has not been assigned yet.
+    /// - `AlreadyLiked` if the caller has liked this message before.
+    pub fn like_message(&mut self, id: U256) -> Result<(), Vec<u8>> {
+        self.require_exists(id)?;
+        let liker = msg::sender();
+        let key = pair_key(id, liker);
+        if self.liked.get(key) {
+            return Err(AlreadyLiked { id, liker }.abi_encode());
+        }
+
+        self.liked.setter(key).set(true);
+        let count = self.likes.get(id);
+        self.likes.setter(id).set(count + U256::from(1));
+        evm::log(MessageLiked { id, liker });
+        Ok(())
+    }
+
+    /// Get the number of distinct addresses that liked a message.
+    ///
+    /// # Errors
+    /// Reverts with `MessageNotFound` if the ID has not been assigned yet.
+    pub fn get_likes(&self, id: U256) -> Result<U256, Vec<u8>> {
+        self.require_exists(id)?;
+        Ok(self.likes.get(id))
+    }
+
+    /// Get the tag a message was filed under (0 = untagged).
+    ///
+    /// # Errors
+    /// Reverts with `MessageNotFound` if the ID has not been assigned yet.
+    pub fn get_tag(&self, id: U256) -> Result<U256, Vec<u8>> {
+        self.require_exists(id)?;
+        Ok(self.tags.get(id))
+    }
+
+    /// Get up to `count` message IDs filed under `tag`, starting at position
+    /// `start` in the tag's index (oldest first).
+    ///
+    /// The page is clamped to the end of the index, so it may return fewer
+    /// than `count` IDs. Tag 0 is not indexed and always returns an empty page.
+    ///
+    /// # Errors
+    /// Reverts with `PageTooLarge` if `count` exceeds `MAX_PAGE_SIZE`.
+    pub fn get_messages_by_tag(
+        &self,
+        tag: U256,
+        start: U256,
+        count: U256,
+    ) -> Result<Vec<U256>, Vec<u8>> {
+        let max = U256::from(MAX_PAGE_SIZE);
+        if count > max {
+            return Err(PageTooLarge {
+                requested: count,
+                max,
+            }
+            .abi_encode());
+        }
+
+        let ids = self.messages_by_tag.getter(tag);
+        let end = start.saturating_add(count).min(U256::from(ids.len()));
+        let mut page = Vec::new();
+        let mut index = start;
+        while index < end {
+            page.extend(ids.get(index));
+            index += U256::from(1);
+        }
+        Ok(page)
+    }
+
+    /// Get the number of messages stored in block `block`.
+    ///
+    /// Counts are bucketed at write time, so this is a single read. Imported
+    /// messages are not counted.
+    pub fn messages_in_block(&self, block: U256) -> U256 {
+        self.block_counts.get(block)
+    }
+
+    /// Get the block timestamp at which a message was stored.
+    ///
+    /// Messages stored before timestamps were recorded return 0.
+    ///
+    /// # Errors
+    /// Reverts with `MessageNotFound` if the ID has not been assigned yet.
+    pub fn get_timestamp(&self, id: U256) -> Result<U256, Vec<u8>> {
+        self.require_exists(id)?;
+        Ok(self.timestamps.get(id))
+    }
+
+    /// Replace the content of a message, keeping the previous text in its
+    /// edit history.
+    ///
+    /// Every edit appends the outgoing content to `edit_history`, so repeated
+    /// edits (even within the same block) each leave their own entry. Like a
+    /// new message, `new_content` is stored with the current `prefix`.
+    ///
+    /// # Errors
+    /// - `MessageNotFound` if the ID does not exist.
+    /// - `MessageTombstoned` if the message was deleted.
+    /// - `NotMessageOwner` if the caller is not the recorded sender.
+    /// - `SenderBlocked` if the caller has been banned.
+    /// - `EmptyMessage` if `new_content` is an empty string.
+    /// - `MessageTooLong` if `new_content` exceeds `max_length` bytes once prefixed.
+    pub fn edit_message(&mut self, id: U256, new_content: String) -> Result<(), Vec<u8>> {
+        self.require_live(id)?;
+        self.require_message_owner(id)?;
+        self.require_not_blocked(msg::sender())?;
+        let (new_content, hash) = self.prepare_content(new_content)?;
+
+        // Archive the current version before overwriting it.
+        let previous = self.messages.getter(id).get_string();
+        self.edit_history.setter(id).grow().set_str(&previous);
+        self.write_content(id, &new_content);
+        self.content_hashes.setter(id).set(hash);
+
+        evm::log(MessageEdited {
+            id,
+            editor: msg::sender(),
+            newContent: new_content,
+        });
+
+        Ok(())
+    }
+
+    /// Overwrite the content of a message in place, without keeping the old
+    /// text in `edit_history`, and emit `MessageReplaced`.
+    ///
+    /// The content hash and timestamp are updated to match the new content,
+    /// which is stored with the current `prefix` like any other send.
+    ///
+    /// # Errors
+    /// Same as `edit_message`.
+    pub fn replace_message(&mut self, id: U256, new_content: String) -> Result<(), Vec<u8>> {
+        self.require_live(id)?;
+        self.require_message_owner(id)?;
+        let sender = msg::sender();
+        self.require_not_blocked(sender)?;
+        let (new_content, hash) = self.prepare_content(new_content)?;
+
+        self.write_content(id, &new_content);
+        self.content_hashes.setter(id).set(hash);
+        let timestamp = U256::from(block::timestamp());
+        self.timestamps.setter(id).set(timestamp);
+
+        evm::log(MessageReplaced { id, sender });
+        Ok(())
+    }
+
+    /// Delete (tombstone) a message.
+    ///
+    /// The ID stays reserved and `message_count` is unchanged, so existing ID
+    /// references remain valid. The stored content and its edit history are
+    /// cleared to refund storage, and subsequent `get_message` calls revert
+    /// with `MessageTombstoned`.
+    ///
+    /// # Errors
+    /// - `MessageNotFound` if the ID does not exist.
+    /// - `MessageTombstoned` if the message was already deleted.
+    /// - `NotMessageOwner` if the caller is not the recorded sender.
+    /// - `SenderBlocked` if the caller has been banned.
+    pub fn delete_message(&mut self, id: U256) -> Result<(), Vec<u8>> {
+        self.require_live(id)?;
+        self.require_message_owner(id)?;
+        self.require_not_blocked(msg::sender())?;
+
+        self.deleted.setter(id).set(true);
+        self.write_content(id, "");
+        self.edit_history.setter(id).erase();
+        self.content_hashes.setter(id).set(U256::ZERO);
+
+        evm::log(MessageDeleted {
+            id,
+            sender: msg::sender(),
+        });
+
+        Ok(())
+    }
+
+    /// Hand a message to a new owner, e.g. a DAO or multisig.
+    ///
+    /// Updates the recorded sender, so the new owner is the one allowed to
+    /// edit or delete the message afterwards. The original author's
+    /// `messages_by_sender` index keeps the ID.
+    ///
+    /// # Errors
+    /// - `ZeroAddress` if `new_owner` is the zero address.
+    /// - `MessageNotFound` if the ID does not exist.
+    /// - `MessageTombstoned` if the message was deleted.
+    /// - `NotMessageOwner` if the caller is not the recorded sender.
+    /// - `SenderBlocked` if the caller has been banned.
+    pub fn transfer_message(&mut self, id: U256, new_owner: Address) -> Result<(), Vec<u8>> {
+        if new_owner.is_zero() {
+            return Err(ZeroAddress {}.abi_encode());
+        }
+        self.require_live(id)?;
+        self.require_message_owner(id)?;
+        self.require_not_blocked(msg::sender())?;
+
+        self.senders.setter(id).set(new_owner);
+
+        evm::log(MessageOwnershipTransferred {
+            id,
+            from: msg::sender(),
+            to: new_owner,
+        });
+
+        Ok(())
+    }
+
+    /// Get all previous versions of a message, oldest first.
+    ///
+    /// Returns an empty list for messages that were never edited or have
+    /// expired.
+    ///
+    /// # Errors
+    /// Reverts with `MessageNotFound` if the ID has not been assigned yet.
+    pub fn get_edit_history(&self, id: U256) -> Result<Vec<String>, Vec<u8>> {
+        self.require_exists(id)?;
+        if self.is_expired(id) {
+            return Ok(Vec::new());
+        }
+        let history = self.edit_history.getter(id);
+        let versions = (0..history.len())
+            .filter_map(|i| history.getter(i).map(|v| v.get_string()))
+            .collect();
+        Ok(versions)
+    }
+
+    /// Get the contract version, so clients can gate features on the
+    /// deployed ABI without an external registry.
+    pub fn version() -> String {
+        String::from(VERSION)
+    }
+
+    /// Get the storage slot holding the content of message `id`, for
+    /// building `eth_getProof` requests.
+    ///
+    /// `messages` is the first storage field, so its map root is slot 0, and
+    /// the SDK keys `StorageMap<U256, _>` like Solidity mappings:
+    /// `keccak256(id_be32 ++ root_be32)`. The content follows Solidity's
+    /// `string` layout from that slot: strings under 32 bytes are stored
+    /// inline with `length * 2` in the low byte, longer ones store
+    /// `length * 2 + 1` there and their data at `keccak256(slot)` onwards.
+    pub fn content_slot(id: U256) -> U256 {
+        let mut packed = [0u8; 64];
+        packed[..32].copy_from_slice(&id.to_be_bytes::<32>());
+        crypto::keccak(packed).into()
+    }
+
+    /// Get the total number of messages stored.
+    ///
+    /// The returned value is also the next ID that will be assigned.
+    pub fn message_count(&self) -> U256 {
+        self.message_count.get()
+    }
+
+    /// Estimate the bytes of storage used by messages:
+    /// `message_count * MESSAGE_OVERHEAD_BYTES` plus the current content
+    /// lengths.
+    ///
+    /// This is a rough O(1) figure for planning migrations and pruning. It
+    /// ignores edit history and the optional per-message maps (tags, likes,
+    /// expiry, and so on), does not round content up to whole slots, and
+    /// only counts content written since the running total was introduced.
+    pub fn estimated_storage_bytes(&self) -> U256 {
+        let overhead = self.message_count.get() * U256::from(MESSAGE_OVERHEAD_BYTES);
+        overhead.saturating_add(self.total_content_bytes.get())
+    }
+
+    /// Get the number of distinct addresses that have posted a message.
+    ///
+    /// Senders are counted from the first post after this counter was
+    /// introduced; earlier posters are counted on their next message.
+    pub fn unique_sender_count(&self) -> U256 {
+        self.unique_senders.get()
+    }
+
+    /// Get the rolling digest committing to every message sent so far.
+    ///
+    /// Starting from 0, each stored message updates it as
+    /// `digest = keccak256(abi.encode(uint256 digest, uint256 id, address sender,
+    /// string content))`, using the standard (non-packed) ABI encoding and the
+    /// `id`, `sender`, and `content` fields of its `MessageSent` event.
+    /// Replaying those events in log order reproduces the value; for
+    /// messages logged as `MessageSentLite`, fetch the content with
+    /// `get_message` first. Imported messages, edits, and deletions do not
+    /// change it.
+    pub fn current_digest(&self) -> U256 {
+        self.digest.get()
+    }
+
+    /// Get the configured `message_count` milestones. Empty means the
+    /// default of every power of ten from 10 upwards.
+    pub fn get_milestones(&self) -> Vec<U256> {
+        (0..self.milestones.len())
+            .filter_map(|i| self.milestones.get(i))
+            .collect()
+    }
+
+    /// Replace the `message_count` values that emit `Milestone`. An empty
+    /// list restores the powers-of-ten default.
+    ///
+    /// Values at or below the current `message_count` are kept but will not
+    /// fire, since the count only moves forward.
+    ///
+    /// # Errors
+    /// - `NotOwner` if the caller is not the owner.
+    /// - `TooManyMilestones` if more than `MAX_MILESTONES` values are given.
+    /// - `MilestonesNotAscending` if the values are not strictly ascending.
+    pub fn set_milestones(&mut self, milestones: Vec<U256>) -> Result<(), Vec<u8>> {
+        self.require_owner()?;
+        if milestones.len() as u64 > MAX_MILESTONES {
+            return Err(TooManyMilestones {
+                max: U256::from(MAX_MILESTONES),
+            }
+            .abi_encode());
+        }
+        if milestones.windows(2).any(|pair| pair[0] >= pair[1]) {
+            return Err(MilestonesNotAscending {}.abi_encode());
+        }
+
+        let count = self.message_count.get();
+        let next = milestones.iter().take_while(|&&m| m <= count).count();
+        self.milestones.erase();
+        for milestone in milestones {
+            self.milestones.push(milestone);
+        }
+        self.next_milestone.set(U256::from(next));
+        Ok(())
+    }
+
+    /// Whether `id` refers to a stored, non-deleted message.
+    ///
+    /// Never reverts, so indexers can probe IDs without handling errors.
+    pub fn exists(&self, id: U256) -> bool {
+        id < self.message_count.get() && !self.deleted.get(id)
+    }
+
+    /// Get the ArbSys ticket ID from the bridge of a message.
+    ///
+    /// Returns 0 if the message has never been bridged.
+    ///
+    /// # Errors
+    /// Reverts with `MessageNotFound` if the ID has not been assigned yet.
+    pub fn get_bridge_ticket(&self, id: U256) -> Result<U256, Vec<u8>> {
+        self.require_exists(id)?;
+        Ok(self.bridge_tickets.get(id))
+    }
+
+    /// Estimate the L1 block at which the bridge with ArbSys ticket
+    /// `ticket_id` becomes executable: the block it was bridged in plus
+    /// `challenge_period`.
+    ///
+    /// On Arbitrum `block::number()` reports an approximate L1 block number,
+    /// and the real deadline depends on when the batch containing the message
+    /// is asserted on L1, so treat the result as a lower-bound estimate only.
+    ///
+    /// # Errors
+    /// Reverts with `UnknownTicket` if no bridge recorded `ticket_id`.
+    pub fn bridge_ready_estimate(&self, ticket_id: U256) -> Result<U256, Vec<u8>> {
+        let bridged_at = self.bridge_blocks.get(ticket_id);
+        if bridged_at.is_zero() {
+            return Err(UnknownTicket { ticket: ticket_id }.abi_encode());
+        }
+        Ok(bridged_at.saturating_add(self.challenge_period()))
+    }
+
+    /// Get the challenge period in L1 blocks used by `bridge_ready_estimate`.
+    pub fn challenge_period(&self) -> U256 {
+        let period = self.challenge_period.get();
+        if period.is_zero() {
+            U256::from(DEFAULT_CHALLENGE_PERIOD)
+        } else {
+            period
+        }
+    }
+
+    /// Set the challenge period in L1 blocks. Zero restores the default.
+    ///
+    /// # Errors
+    /// Reverts with `NotOwner` if the caller is not the owner.
+    pub fn set_challenge_period(&mut self, period: U256) -> Result<(), Vec<u8>> {
+        self.require_owner()?;
+        self.challenge_period.set(period);
+        Ok(())
+    }
+
+    /// Whether a message has been bridged to L1.
+    ///
+    /// Returns false for unassigned IDs rather than reverting.
+    pub fn is_bridged(&self, id: U256) -> bool {
+        self.bridged.get(id)
+    }
+
+    /// Bridge a stored message to L1 via the ArbSys precompile.
+    ///
+    /// Thin wrapper around `bridge_message_to` with the L1 destination set to
+    /// `msg::sender()`, so the caller will be the recipient on L1. Returns the
+    /// ArbSys ticket ID.
+    ///
+    /// # Errors
+    /// Same as `bridge_message_to`.
+    #[payable]
+    pub fn bridge_message(&mut self, id: U256) -> Result<U256, Vec<u8>> {
+        self.bridge_message_to(id, msg::sender())
+    }
+
+    /// Bridge a stored message to an arbitrary L1 `destination`.
+    ///
+    /// Calls `ArbSys.sendTxToL1()` which enqueues an L2-to-L1 message that
+    /// becomes executable on L1 after the challenge period elapses. The L1
+    /// payload is the raw UTF-8 content bytes (`ENCODING_RAW`). The returned
+    /// ticket ID is also recorded in `bridge_tickets`.
+    ///
+    /// Each message can be bridged once. The `bridged` flag is only set after
+    /// ArbSys accepts the call, so a failed bridge can be retried.
+    ///
+    /// Payable: any attached value is forwarded through `sendTxToL1` to the L1
+    /// destination and reported with `BridgeValueForwarded`.
+    ///
+    /// # Errors
+    /// - `ContractPaused` if the owner has paused the contract.
+    /// - `InvalidDestination` if `destination` is the zero address.
+    /// - `DestinationNotAllowed` if `destination` is not whitelisted while
+    ///   `bridge_restricted` is on.
+    /// - `MessageNotFound` if the ID does not exist.
+    /// - `MessageTombstoned` if the message was deleted.
+    /// - `AlreadyBridged` if the message was bridged before.
+    /// - `BridgeCallFailed` if the ArbSys call reverts.
+    /// - `Reentrancy` if called while another bridge is in progress.
+    /// - `SenderBlocked` if the caller has been banned.
+    #[payable]
+    pub fn bridge_message_to(&mut self, id: U256, destination: Address) -> Result<U256, Vec<u8>> {
+        self.bridge_stored(id, destination, ENCODING_RAW, FixedBytes::ZERO)
+    }
+
+    /// Bridge caller-supplied `content` to `destination` on L1 without
+    /// storing it, returning the ArbSys ticket ID and emitting `RawBridged`.
+    ///
+    /// The payload is the raw UTF-8 bytes, as with `bridge_message_to`. No
+    /// message ID is assigned and no fee is charged, since nothing is
+    /// written to L2 storage.
+    ///
+    /// # Errors
+    /// - `ContractPaused` if the owner has paused the contract.
+    /// - `SenderBlocked` if the caller has been banned.
+    /// - `InvalidDestination` if `destination` is the zero address.
+    /// - `DestinationNotAllowed` if `destination` is not whitelisted while
+    ///   `bridge_restricted` is on.
+    /// - `EmptyMessage` if `content` is empty.
+    /// - `Reentrancy` if called while another bridge is in progress.
+    /// - `BridgeCallFailed` if the ArbSys call reverts.
+    pub fn bridge_raw(&mut self, content: String, destination: Address) -> Result<U256, Vec<u8>> {
+        self.require_not_paused()?;
+        self.require_not_blocked(msg::sender())?;
+        self.require_bridge_destination(destination)?;
+        if content.is_empty() {
+            return Err(EmptyMessage {}.abi_encode());
+        }
+
+        self.acquire_lock()?;
+        let result = self.send_to_l1(destination, content.into_bytes(), U256::ZERO);
+        self.locked.set(false);
+        let ticket_id = result?;
+
+        let ticket: [u8; 32] = ticket_id.to_be_bytes();
+        evm::log(RawBridged {
+            destination,
+            ticket: ticket.into(),
+        });
+        Ok(ticket_id)
+    }
+
+    /// Bridge a stored message to `msg::sender()` on L1 as an ABI-encoded
+    /// tuple (`ENCODING_ABI`).
+    ///
+    /// The payload is `abi.encode(uint256 id, address sender, string content)`,
+    /// so an L1 executor can `abi.decode(data, (uint256, address, string))`.
+    /// Otherwise identical to `bridge_message`, including the once-only rule.
+    ///
+    /// # Errors
+    /// Same as `bridge_message_to`.
+    #[payable]
+    pub fn bridge_message_encoded(&mut self, id: U256) -> Result<U256, Vec<u8>> {
+        self.bridge_stored(id, msg::sender(), ENCODING_ABI, FixedBytes::ZERO)
+    }
+
+    /// Bridge a stored message to `msg::sender()` on L1 as a call to the
+    /// function identified by `selector` (`ENCODING_CALL`).
+    ///
+    /// The payload is `selector ++ abi.encode(uint256 id, string content)`, so
+    /// executing the outbox message after the challenge period calls
+    /// `<caller>.<selector>(id, content)` on L1 directly. Otherwise identical
+    /// to `bridge_message`, including value forwarding and the once-only rule;
+    /// `selector` is reported in `MessageBridged`.
+    ///
+    /// # Errors
+    /// Same as `bridge_message_to`.
+    #[payable]
+    pub fn bridge_message_call(
+        &mut self,
+        id: U256,
+        selector: FixedBytes<4>,
+    ) -> Result<U256, Vec<u8>> {
+        self.bridge_stored(id, msg::sender(), ENCODING_CALL, selector)
+    }
+
+    /// Store a new message and immediately bridge it to `msg::sender()` on L1
+    /// as raw content bytes, returning `(message ID, ticket ID)`.
+    ///
+    /// The message write is flushed before the ArbSys call and the bridge
+    /// holds the reentrancy guard. The attached value pays `send_fee`; none is
+    /// forwarded to L1.
+    ///
+    /// If the bridge fails, the message is still stored: the call succeeds
+    /// with a ticket ID of 0 and emits `BridgeAttemptFailed` carrying the
+    /// bridge error, and the message can be bridged later with
+    /// `bridge_message`. (An `Err` return would revert the stored message
+    /// too.)
+    ///
+    /// # Errors
+    /// Same as `send_message`; bridge failures are reported as above.
+    #[payable]
+    pub fn send_and_bridge(&mut self, content: String) -> Result<(U256, U256), Vec<u8>> {
+        let id = self.send_from_caller(content)?;
+
+        let bridged = self.acquire_lock().and_then(|()| {
+            let result = self.bridge_unlocked(
+                id,
+                msg::sender(),
+                ENCODING_RAW,
+                FixedBytes::ZERO,
+                U256::ZERO,
+            );
+            self.locked.set(false);
+            result
+        });
+
+        match bridged {
+            Ok(ticket_id) => Ok((id, ticket_id)),
+            Err(reason) => {
+                evm::log(BridgeAttemptFailed {
+                    id,
+                    reason: reason.into(),
+                });
+                Ok((id, U256::ZERO))
+            }
+        }
+    }
+
+    /// Whether `bridge_message(id)` from the caller would currently get as far
+    /// as the ArbSys call, without making it.
+    ///
+    /// Checks the pause flag, the caller's blocklist status, the caller as an
+    /// L1 destination under the bridge whitelist, that the message is neither
+    /// deleted nor expired, and that it has not been bridged.
+    ///
+    /// # Errors
+    /// Reverts with `MessageNotFound` if the ID has not been assigned yet.
+    pub fn can_bridge(&self, id: U256) -> Result<bool, Vec<u8>> {
+        self.require_exists(id)?;
+        Ok(!self.paused.get()
+            && !self.blocked.get(msg::sender())
+            && self.require_bridge_destination(msg::sender()).is_ok()
+            && self.require_live(id).is_ok()
+            && !self.bridged.get(id))
+    }
+
+    /// Get the size in bytes of the L1 payload `bridge_message` would send for
+    /// message `id`, without calling ArbSys.
+    ///
+    /// # Errors
+    /// Reverts with `MessageNotFound` if the ID has not been assigned yet.
+    pub fn estimate_bridge_size(&self, id: U256) -> Result<U256, Vec<u8>> {
+        self.require_exists(id)?;
+        let payload = self.bridge_payload(id, ENCODING_RAW, FixedBytes::ZERO);
+        Ok(U256::from(payload.len()))
+    }
+
+    /// Bridge several stored messages to `msg::sender()` on L1 in one call,
+    /// returning their ticket IDs in order.
+    ///
+    /// Every ID is validated before any ArbSys call is made, and the storage
+    /// cache is flushed once up front. Payloads are raw content bytes
+    /// (`ENCODING_RAW`), exactly as `bridge_message` would send them, and no
+    /// value is forwarded.
+    ///
+    /// # Errors
+    /// - `BatchTooLarge` if more than `MAX_BATCH_SIZE` IDs are given.
+    /// - `AlreadyBridged` if an ID was bridged before or appears twice.
+    /// - `BridgeCallFailed` with the batch index of the first failing call;
+    ///   the whole batch is reverted.
+    /// - Any other error `bridge_message` can return.
+    pub fn bridge_messages(&mut self, ids: Vec<U256>) -> Result<Vec<U256>, Vec<u8>> {
+        self.require_batch_size(ids.len())?;
+        self.acquire_lock()?;
+        let result = self.bridge_batch_unlocked(&ids);
+        self.locked.set(false);
+        result
+    }
+
+    /// Bridge one stored message to several L1 `destinations`, returning one
+    /// ticket ID per destination in order.
+    ///
+    /// The storage cache is flushed once, then `sendTxToL1` is called per
+    /// destination with the raw content bytes (`ENCODING_RAW`) and no value.
+    /// This counts as the message's one bridge: `bridge_tickets` records the
+    /// first ticket, and one `MessageBridged` is emitted per destination.
+    ///
+    /// # Errors
+    /// - `BatchTooLarge` if more than `MAX_BATCH_SIZE` destinations are given.
+    /// - `InvalidDestination` if any destination is the zero address.
+    /// - `DestinationNotAllowed` if any destination is not whitelisted while
+    ///   `bridge_restricted` is on.
+    /// - `BridgeCallFailed` with the index of the first failing destination;
+    ///   all bridges are reverted.
+    /// - Any other error `bridge_message` can return.
+    pub fn bridge_message_multi(
+        &mut self,
+        id: U256,
+        destinations: Vec<Address>,
+    ) -> Result<Vec<U256>, Vec<u8>> {
+        self.require_batch_size(destinations.len())?;
+        self.acquire_lock()?;
+        let result = self.bridge_multi_unlocked(id, &destinations);
+        self.locked.set(false);
+        result
+    }
+
+    /// Bridge several stored messages to `destination` as a single L1
+    /// message, returning its one ticket ID and emitting `CombinedBridged`.
+    ///
+    /// The payload is `abi.encode((uint256 id, address sender, string
+    /// content)[])` in the order given. This is an archival copy: it does not
+    /// count as any message's one bridge, so `bridged` and `bridge_tickets`
+    /// are left untouched and IDs may repeat.
+    ///
+    /// # Errors
+    /// - `BatchTooLarge` if more than `MAX_BATCH_SIZE` IDs are given.
+    /// - `MessageNotFound`, `MessageTombstoned`, or `MessageExpired` for the
+    ///   first ID that cannot be read; nothing is bridged.
+    /// - `BridgeCallFailed` if the ArbSys call reverts.
+    /// - `ContractPaused`, `SenderBlocked`, `InvalidDestination`,
+    ///   `DestinationNotAllowed`, and `Reentrancy` as for `bridge_message_to`.
+    pub fn bridge_combined(
+        &mut self,
+        ids: Vec<U256>,
+        destination: Address,
+    ) -> Result<U256, Vec<u8>> {
+        self.require_batch_size(ids.len())?;
+        self.require_not_paused()?;
+        self.require_not_blocked(msg::sender())?;
+        self.require_bridge_destination(destination)?;
+
+        let mut entries = Vec::with_capacity(ids.len());
+        for &id in &ids {
+            self.require_live(id)?;
+            entries.push((
+                id,
+                self.senders.get(id),
+                self.messages.getter(id).get_string(),
+            ));
+        }
+        let data = entries.abi_encode();
+
+        self.acquire_lock()?;
+        let result = self.send_to_l1(destination, data, U256::ZERO);
+        self.locked.set(false);
+        let ticket_id = result?;
+
+        let bridge_tx_hash: [u8; 32] = ticket_id.to_be_bytes();
+        evm::log(CombinedBridged {
+            ids,
+            destination,
+            bridgeTxHash: bridge_tx_hash.into(),
+        });
+        Ok(ticket_id)
+    }
+
+    /// Set the contract owner. Can only be called once.
+    ///
+    /// There is no constructor, so this should be called in the same
+    /// deployment flow as activation to avoid being front-run.
+    ///
+    /// # Errors
+    /// - `AlreadyInitialized` if the owner has already been set.
+    /// - `ZeroAddress` if `owner` is the zero address.
+    pub fn init(&mut self, owner: Address) -> Result<(), Vec<u8>> {
+        if !self.owner.get().is_zero() {
+            return Err(AlreadyInitialized {}.abi_encode());
+        }
+        if owner.is_zero() {
+            return Err(ZeroAddress {}.abi_encode());
+        }
+        self.owner.set(owner);
+        Ok(())
+    }
+
+    /// Get the contract owner (zero until `init` is called).
+    pub fn owner(&self) -> Address {
+        self.owner.get()
+    }
+
+    /// Get the owner nominated by `transfer_ownership` (zero if none).
+    pub fn pending_owner(&self) -> Address {
+        self.pending_owner.get()
+    }
+
+    /// Start handing ownership to `new_owner`, who must then call
+    /// `accept_ownership`. The current owner keeps control until then.
+    ///
+    /// Nominating the zero address cancels a pending transfer.
+    ///
+    /// # Errors
+    /// Reverts with `NotOwner` if the caller is not the owner.
+    pub fn transfer_ownership(&mut self, new_owner: Address) -> Result<(), Vec<u8>> {
+        self.require_owner()?;
+        self.pending_owner.set(new_owner);
+        evm::log(OwnershipTransferStarted {
+            previousOwner: self.owner.get(),
+            newOwner: new_owner,
+        });
+        Ok(())
+    }
+
+    /// Complete a transfer started by `transfer_ownership`.
+    ///
+    /// # Errors
+    /// Reverts with `NotPendingOwner` unless the caller is the pending owner.
+    pub fn accept_ownership(&mut self) -> Result<(), Vec<u8>> {
+        let caller = msg::sender();
+        let pending = self.pending_owner.get();
+        if pending.is_zero() || caller != pending {
+            return Err(NotPendingOwner { caller }.abi_encode());
+        }
+
+        let previous = self.owner.get();
+        self.owner.set(caller);
+        self.pending_owner.set(Address::ZERO);
+        evm::log(OwnershipTransferred {
+            previousOwner: previous,
+            newOwner: caller,
+        });
+        Ok(())
+    }
+
+    /// Whether sends and bridges are currently paused.
+    pub fn paused(&self) -> bool {
+        self.paused.get()
+    }
+
+    /// Pause message submission and bridging. Reads remain available.
+    ///
+    /// # Errors
+    /// Reverts with `NotOwner` if the caller is not the owner.
+    pub fn pause(&mut self) -> Result<(), Vec<u8>> {
+        self.require_owner()?;
+        self.paused.set(true);
+        evm::log(Paused {
+            account: msg::sender(),
+        });
+        Ok(())
+    }
+
+    /// Resume message submission and bridging.
+    ///
+    /// # Errors
+    /// Reverts with `NotOwner` if the caller is not the owner.
+    pub fn unpause(&mut self) -> Result<(), Vec<u8>> {
+        self.require_owner()?;
+        self.paused.set(false);
+        evm::log(Unpaused {
+            account: msg::sender(),
+        });
+        Ok(())
+    }
+
+    /// Whether message submission has been permanently frozen.
+    pub fn frozen(&self) -> bool {
+        self.frozen.get()
+    }
+
+    /// Permanently stop accepting new messages. Unlike `pause`, this cannot
+    /// be undone. Reads, edits, and bridging of existing messages remain
+    /// available.
+    ///
+    /// Calling it again after freezing is a no-op and emits nothing.
+    ///
+    /// # Errors
+    /// Reverts with `NotOwner` if the caller is not the owner.
+    pub fn freeze(&mut self) -> Result<(), Vec<u8>> {
+        self.require_owner()?;
+        if self.frozen.get() {
+            return Ok(());
+        }
+        self.frozen.set(true);
+        evm::log(Frozen { by: msg::sender() });
+        Ok(())
+    }
+
+    /// Get the fee in wei required per message.
+    pub fn send_fee(&self) -> U256 {
+        self.send_fee.get()
+    }
+
+    /// Set the fee in wei required per message. Zero disables the fee.
+    ///
+    /// # Errors
+    /// Reverts with `NotOwner` if the caller is not the owner.
+    pub fn set_send_fee(&mut self, amount: U256) -> Result<(), Vec<u8>> {
+        self.require_owner()?;
+        self.send_fee.set(amount);
+        Ok(())
+    }
+
+    /// Get the fees collected since the last `withdraw`, in wei.
+    ///
+    /// Tracked separately from `contract::balance()`, so value forwarded
+    /// through bridges or sent to the contract directly does not skew it.
+    pub fn collected_fees(&self) -> U256 {
+        self.collected_fees.get()
+    }
+
+    /// Transfer all `collected_fees` to `to` and reset the accumulator.
+    ///
+    /// # Errors
+    /// - `NotOwner` if the caller is not the owner.
+    /// - `ZeroAddress` if `to` is the zero address.
+    /// - Whatever the recipient reverts with if it rejects the transfer.
+    #[allow(deprecated)]
+    pub fn withdraw(&mut self, to: Address) -> Result<(), Vec<u8>> {
+        self.require_owner()?;
+        if to.is_zero() {
+            return Err(ZeroAddress {}.abi_encode());
+        }
+        let amount = self.collected_fees.get();
+        self.collected_fees.set(U256::ZERO);
+        call::transfer_eth(to, amount)
+    }
+
+    /// Get the maximum message length in bytes.
+    pub fn max_length(&self) -> U256 {
+        let max = self.max_length.get();
+        if max.is_zero() {
+            U256::from(DEFAULT_MAX_LENGTH)
+        } else {
+            max
+        }
+    }
+
+    /// Set the maximum message length in bytes. Zero restores the default.
+    ///
+    /// # Errors
+    /// Reverts with `NotOwner` if the caller is not the owner.
+    pub fn set_max_length(&mut self, max: U256) -> Result<(), Vec<u8>> {
+        self.require_owner()?;
+        self.max_length.set(max);
+        Ok(())
+    }
+
+    /// Get the per-sender message quota (0 = unlimited).
+    pub fn max_per_sender(&self) -> U256 {
+        self.max_per_sender.get()
+    }
+
+    /// Set the most messages any one address may send and emit
+    /// `QuotaUpdated`. Zero removes the limit. Lowering it below what a
+    /// sender has already posted blocks further sends but keeps their
+    /// messages.
+    ///
+    /// # Errors
+    /// Reverts with `NotOwner` if the caller is not the owner.
+    pub fn set_max_per_sender(&mut self, max: U256) -> Result<(), Vec<u8>> {
+        self.require_owner()?;
+        self.max_per_sender.set(max);
+        evm::log(QuotaUpdated { max });
+        Ok(())
+    }
+
+    /// Get how many more messages `addr` may send under `max_per_sender`,
+    /// or `U256::MAX` while the quota is unlimited.
+    pub fn sends_remaining(&self, addr: Address) -> U256 {
+        let max = self.max_per_sender.get();
+        if max.is_zero() {
+            return U256::MAX;
+        }
+        max.saturating_sub(self.sent_counts.get(addr))
+    }
+
+    /// Whether empty messages are accepted.
+    pub fn allow_empty(&self) -> bool {
+        self.allow_empty.get()
+    }
+
+    /// Accept or reject empty messages and emit `AllowEmptyUpdated`. Off by
+    /// default, in which case empty content reverts with `EmptyMessage`.
+    ///
+    /// # Errors
+    /// Reverts with `NotOwner` if the caller is not the owner.
+    pub fn set_allow_empty(&mut self, allowed: bool) -> Result<(), Vec<u8>> {
+        self.require_owner()?;
+        self.allow_empty.set(allowed);
+        evm::log(AllowEmptyUpdated { allowed });
+        Ok(())
+    }
+
+    /// Whether content encoding checks are enabled.
+    pub fn validate_utf8(&self) -> bool {
+        self.validate_utf8.get()
+    }
+
+    /// Turn content encoding checks on or off and emit `ValidateUtf8Updated`.
+    ///
+    /// Incoming strings are always valid UTF-8, so when enabled the check
+    /// rejects control characters (other than tab, CR, and LF) with
+    /// `InvalidEncoding`. Unicode normalization is not enforced on-chain; the
+    /// tables would dwarf the rest of the contract. Off by default.
+    ///
+    /// # Errors
+    /// Reverts with `NotOwner` if the caller is not the owner.
+    pub fn set_validate_utf8(&mut self, enabled: bool) -> Result<(), Vec<u8>> {
+        self.require_owner()?;
+        self.validate_utf8.set(enabled);
+        evm::log(ValidateUtf8Updated { enabled });
+        Ok(())
+    }
+
+    /// Whether whitespace-only content is rejected as empty.
+    pub fn reject_whitespace(&self) -> bool {
+        self.reject_whitespace.get()
+    }
+
+    /// Treat whitespace-only content as empty, or stop doing so, and emit
+    /// `RejectWhitespaceUpdated`. Off by default. Content that passes is
+    /// stored untrimmed. Has no effect while `allow_empty` is set.
+    ///
+    /// # Errors
+    /// Reverts with `NotOwner` if the caller is not the owner.
+    pub fn set_reject_whitespace(&mut self, enabled: bool) -> Result<(), Vec<u8>> {
+        self.require_owner()?;
+        self.reject_whitespace.set(enabled);
+        evm::log(RejectWhitespaceUpdated { enabled });
+        Ok(())
+    }
+
+    /// Whether sends emit the full `MessageSent` event (the default) rather
+    /// than `MessageSentLite`.
+    pub fn emit_content(&self) -> bool {
+        !self.omit_content.get()
+    }
+
+    /// Choose between `MessageSent`, which logs the content, and the cheaper
+    /// `MessageSentLite`, which logs only the ID and sender, and emit
+    /// `EmitContentUpdated`. Indexers relying on content in logs must fall
+    /// back to `get_message` while this is off.
+    ///
+    /// # Errors
+    /// Reverts with `NotOwner` if the caller is not the owner.
+    pub fn set_emit_content(&mut self, enabled: bool) -> Result<(), Vec<u8>> {
+        self.require_owner()?;
+        self.omit_content.set(!enabled);
+        evm::log(EmitContentUpdated { enabled });
+        Ok(())
+    }
+
+    /// Get the soft length limit in bytes (0 = disabled).
+    pub fn soft_limit(&self) -> U256 {
+        self.soft_limit.get()
+    }
+
+    /// Set the soft length limit in bytes. Messages longer than this are
+    /// still stored but emit `MessageOverSoftLimit`; `max_length` remains the
+    /// hard limit. Zero disables the warning.
+    ///
+    /// # Errors
+    /// Reverts with `NotOwner` if the caller is not the owner.
+    pub fn set_soft_limit(&mut self, limit: U256) -> Result<(), Vec<u8>> {
+        self.require_owner()?;
+        self.soft_limit.set(limit);
+        Ok(())
+    }
+
+    /// Get the minimum number of blocks between posts by one sender.
+    pub fn min_block_gap(&self) -> U256 {
+        self.min_block_gap.get()
+    }
+
+    /// Set the minimum number of blocks between posts by one sender.
+    /// Zero disables rate limiting.
+    ///
+    /// # Errors
+    /// Reverts with `NotOwner` if the caller is not the owner.
+    pub fn set_min_block_gap(&mut self, gap: U256) -> Result<(), Vec<u8>> {
+        self.require_owner()?;
+        self.min_block_gap.set(gap);
+        Ok(())
+    }
+
+    /// Get the ArbSys address used for bridging: the stored override, or the
+    /// `ARBSYS_ADDR` precompile when none is set.
+    pub fn arbsys_addr(&self) -> Address {
+        let addr = self.arbsys_addr.get();
+        if addr.is_zero() {
+            ARBSYS_ADDR
+        } else {
+            addr
+        }
+    }
+
+    /// Point bridging at a different `IArbSys` implementation, such as a mock
+    /// in tests, and emit `ArbSysUpdated`. The zero address restores the
+    /// precompile.
+    ///
+    /// # Errors
+    /// Reverts with `NotOwner` if the caller is not the owner.
+    pub fn set_arbsys_addr(&mut self, addr: Address) -> Result<(), Vec<u8>> {
+        self.require_owner()?;
+        self.arbsys_addr.set(addr);
+        evm::log(ArbSysUpdated {
+            arbsys: self.arbsys_addr(),
+        });
+        Ok(())
+    }
+
+    /// Whether bridging is limited to whitelisted L1 destinations.
+    pub fn bridge_restricted(&self) -> bool {
+        self.bridge_restricted.get()
+    }
+
+    /// Turn the bridge destination whitelist on or off and emit
+    /// `BridgeRestrictedUpdated`. Off by default, in which case any non-zero
+    /// destination is accepted.
+    ///
+    /// # Errors
+    /// Reverts with `NotOwner` if the caller is not the owner.
+    pub fn set_bridge_restricted(&mut self, restricted: bool) -> Result<(), Vec<u8>> {
+        self.require_owner()?;
+        self.bridge_restricted.set(restricted);
+        evm::log(BridgeRestrictedUpdated { restricted });
+        Ok(())
+    }
+
+    /// Whether `destination` is on the bridge whitelist. Entries only take
+    /// effect while `bridge_restricted` is on.
+    pub fn is_bridge_allowed(&self, destination: Address) -> bool {
+        self.bridge_allowed.get(destination)
+    }
+
+    /// Add or remove `destination` from the bridge whitelist and emit
+    /// `BridgeDestinationUpdated`.
+    ///
+    /// # Errors
+    /// Reverts with `NotOwner` if the caller is not the owner.
+    pub fn set_bridge_allowed(
+        &mut self,
+        destination: Address,
+        allowed: bool,
+    ) -> Result<(), Vec<u8>> {
+        self.require_owner()?;
+        self.bridge_allowed.setter(destination).set(allowed);
+        evm::log(BridgeDestinationUpdated {
+            destination,
+            allowed,
+        });
+        Ok(())
+    }
+
+    /// Get the pinned message IDs, in the order they were pinned.
+    pub fn get_pinned(&self) -> Vec<U256> {
+        (0..self.pinned.len())
+            .filter_map(|i| self.pinned.get(i))
+            .collect()
+    }
+
+    /// Pin message `id` and emit `MessagePinned`. Pinning an already pinned
+    /// message does nothing.
+    ///
+    /// # Errors
+    /// - `NotOwner` if the caller is not the owner.
+    /// - `MessageNotFound` if the ID has not been assigned yet.
+    /// - `TooManyPins` if `MAX_PINS` messages are already pinned.
+    pub fn pin_message(&mut self, id: U256) -> Result<(), Vec<u8>> {
+        self.require_owner()?;
+        self.require_exists(id)?;
+        if self.get_pinned().contains(&id) {
+            return Ok(());
+        }
+        if self.pinned.len() as u64 >= MAX_PINS {
+            return Err(TooManyPins {
+                max: U256::from(MAX_PINS),
+            }
+            .abi_encode());
+        }
+
+        self.pinned.push(id);
+        evm::log(MessagePinned { id });
+        Ok(())
+    }
+
+    /// Unpin message `id` and emit `MessageUnpinned`, keeping the order of
+    /// the remaining pins. Unpinning a message that is not pinned does
+    /// nothing.
+    ///
+    /// # Errors
+    /// Reverts with `NotOwner` if the caller is not the owner.
+    pub fn unpin_message(&mut self, id: U256) -> Result<(), Vec<u8>> {
+        self.require_owner()?;
+        let pins = self.get_pinned();
+        let Some(index) = pins.iter().position(|&pin| pin == id) else {
+            return Ok(());
+        };
+
+        // Shift later pins down one place, then drop the last slot.
+        for (i, &pin) in pins.iter().enumerate().skip(index + 1) {
+            if let Some(mut slot) = self.pinned.setter(i - 1) {
+                slot.set(pin);
+            }
+        }
+        self.pinned.pop();
+
+        evm::log(MessageUnpinned { id });
+        Ok(())
+    }
+
+    /// Get the namespace prefix prepended to all stored content.
+    pub fn get_prefix(&self) -> String {
+        self.prefix.get_string()
+    }
+
+    /// Set the namespace prefix and emit `PrefixUpdated`. An empty string
+    /// disables namespacing.
+    ///
+    /// Applies to messages sent or edited afterwards; existing content is
+    /// not rewritten. The prefix counts towards `max_length`.
+    ///
+    /// # Errors
+    /// Reverts with `NotOwner` if the caller is not the owner.
+    pub fn set_prefix(&mut self, prefix: String) -> Result<(), Vec<u8>> {
+        self.require_owner()?;
+        self.prefix.set_str(&prefix);
+        evm::log(PrefixUpdated { prefix });
+        Ok(())
+    }
+
+    /// Whether only allowlisted addresses may post.
+    pub fn allowlist_enabled(&self) -> bool {
+        self.allowlist_enabled.get()
+    }
+
+    /// Turn allowlist mode on or off. Off (the default) lets anyone post.
+    ///
+    /// # Errors
+    /// Reverts with `NotOwner` if the caller is not the owner.
+    pub fn set_allowlist_enabled(&mut self, enabled: bool) -> Result<(), Vec<u8>> {
+        self.require_owner()?;
+        self.allowlist_enabled.set(enabled);
+        Ok(())
+    }
+
+    /// Whether `addr` is approved to post while allowlist mode is on.
+    pub fn is_allowed(&self, addr: Address) -> bool {
+        self.allowed.get(addr)
+    }
+
+    /// Approve or revoke `addr` for posting in allowlist mode, and emit
+    /// `AllowlistUpdated`.
+    ///
+    /// # Errors
+    /// Reverts with `NotOwner` if the caller is not the owner.
+    pub fn set_allowed(&mut self, addr: Address, allowed: bool) -> Result<(), Vec<u8>> {
+        self.require_owner()?;
+        self.allowed.setter(addr).set(allowed);
+        evm::log(AllowlistUpdated { addr, allowed });
+        Ok(())
+    }
+
+    /// Whether `addr` has been banned.
+    pub fn is_blocked(&self, addr: Address) -> bool {
+        self.blocked.get(addr)
+    }
+
+    /// Ban or unban `addr`, and emit `BlocklistUpdated`.
+    ///
+    /// A banned address cannot send, edit, delete, transfer, or bridge
+    /// messages, even with allowlist mode off. Its existing messages stay
+    /// readable.
+    ///
+    /// # Errors
+    /// Reverts with `NotOwner` if the caller is not the owner.
+    pub fn set_blocked(&mut self, addr: Address, blocked: bool) -> Result<(), Vec<u8>> {
+        self.require_owner()?;
+        self.blocked.setter(addr).set(blocked);
+        evm::log(BlocklistUpdated { addr, blocked });
+        Ok(())
+    }
+
+    /// Whether content with keccak256 hash `hash` is banned.
+    pub fn is_banned_hash(&self, hash: U256) -> bool {
+        self.banned_hashes.get(hash)
+    }
+
+    /// Ban or unban content by its keccak256 hash, and emit
+    /// `ContentBanUpdated`.
+    ///
+    /// The hash is taken over the content as it would be stored, including
+    /// any namespace `prefix`, so it matches `get_content_hash`. Banned
+    /// content is rejected by every send and edit path; messages already
+    /// stored are not affected.
+    ///
+    /// # Errors
+    /// Reverts with `NotOwner` if the caller is not the owner.
+    pub fn set_banned_hash(&mut self, hash: U256, banned: bool) -> Result<(), Vec<u8>> {
+        self.require_owner()?;
+        self.banned_hashes.setter(hash).set(banned);
+        evm::log(ContentBanUpdated { hash, banned });
+        Ok(())
+    }
+
+    /// Export up to `count` messages starting at ID `start` for migration.
+    ///
+    /// Each entry is `(id, sender, content, timestamp)`. The range is clamped
+    /// at `message_count`, so a migration script can page until it receives
+    /// a short page. Expired messages are exported with empty content.
+    ///
+    /// # Errors
+    /// - `NotOwner` if the caller is not the owner.
+    /// - `PageTooLarge` if `count` exceeds `MAX_PAGE_SIZE`.
+    pub fn export_range(&self, start: U256, count: U256) -> Result<Vec<ExportEntry>, Vec<u8>> {
+        self.require_owner()?;
+        let max = U256::from(MAX_PAGE_SIZE);
+        if count > max {
+            return Err(PageTooLarge {
+                requested: count,
+                max,
+            }
+            .abi_encode());
+        }
+
+        let end = start.saturating_add(count).min(self.message_count.get());
+        let mut page = Vec::new();
+        let mut id = start;
+        while id < end {
+            page.push((
+                id,
+                self.senders.get(id),
+                self.visible_content(id),
+                self.timestamps.get(id),
+            ));
+            id += U256::from(1);
+        }
+        Ok(page)
+    }
+
+    /// Write a message exported from a previous deployment.
+    ///
+    /// Stores the entry verbatim under `id` (no content validation and no
+    /// `MessageSent` event), indexes it under `sender`, and raises
+    /// `message_count` to `id + 1` if needed.
+    ///
+    /// # Errors
+    /// - `NotOwner` if the caller is not the owner.
+    /// - `ContractFrozen` if the contract has been frozen.
+    /// - `ZeroAddress` if `sender` is the zero address.
+    /// - `IdAlreadyUsed` if a message is already stored under `id`.
+    /// - `IdOutOfRange` if `id` is `U256::MAX`.
+    pub fn import_message(
+        &mut self,
+        id: U256,
+        sender: Address,
+        content: String,
+        timestamp: U256,
+    ) -> Result<(), Vec<u8>> {
+        self.require_owner()?;
+        self.require_not_frozen()?;
+        if sender.is_zero() {
+            return Err(ZeroAddress {}.abi_encode());
+        }
+        // Every stored message has a non-zero sender, so that marks occupancy.
+        if !self.senders.get(id).is_zero() {
+            return Err(IdAlreadyUsed { id }.abi_encode());
+        }
+        let next = id
+            .checked_add(U256::from(1))
+            .ok_or_else(|| IdOutOfRange { id }.abi_encode())?;
+
+        self.write_content(id, &content);
+        self.content_hashes.setter(id).set(content_hash(&content));
+        self.senders.setter(id).set(sender);
+        self.timestamps.setter(id).set(timestamp);
+        self.messages_by_sender.setter(sender).push(id);
+
+        // Imports may arrive out of order; keep the highest ID as latest.
+        let mut latest = self.latest_by_sender.setter(sender);
+        if id >= latest.get() {
+            latest.set(next);
+        }
+
+        if id >= self.message_count.get() {
+            self.message_count.set(next);
+        }
+        Ok(())
+    }
+}
+
+// ---------------------------------------------------------------------------
+// Internal helpers (not exposed in the ABI)
+// ---------------------------------------------------------------------------
+
+impl MessagingContract {
+    /// Revert with `MessageNotFound` unless `id` has been assigned.
+    fn require_exists(&self, id: U256) -> Result<(), Vec<u8>> {
+        if id >= self.message_count.get() {
+            return Err(MessageNotFound { id }.abi_encode());
+        }
+        Ok(())
+    }
+
+    /// Revert unless `id` has been assigned and has neither been deleted nor
+    /// expired.
+    fn require_live(&self, id: U256) -> Result<(), Vec<u8>> {
+        self.require_exists(id)?;
+        if self.deleted.get(id) {
+            return Err(MessageTombstoned { id }.abi_encode());
+        }
+        if self.is_expired(id) {
+            return Err(MessageExpired { id }.abi_encode());
+        }
+        Ok(())
+    }
+
+    /// Whether message `id` has an expiry block that has already passed.
+    fn is_expired(&self, id: U256) -> bool {
+        let expires_at = self.expiry.get(id);
+        !expires_at.is_zero() && U256::from(block::number()) > expires_at
+    }
+
+    /// The content the non-reverting views report for message `id`: empty
+    /// once it has expired, like the already-empty content of a deleted one.
+    fn visible_content(&self, id: U256) -> String {
+        if self.is_expired(id) {
+            return String::new();
+        }
+        self.messages.getter(id).get_string()
+    }
+
+    /// Revert with `NotMessageOwner` unless the caller is the recorded sender.
+    fn require_message_owner(&self, id: U256) -> Result<(), Vec<u8>> {
+        let caller = msg::sender();
+        if !self.owns_message(id, caller) {
+            return Err(NotMessageOwner { id, caller }.abi_encode());
+        }
+        Ok(())
+    }
+
+    /// Whether `addr` is the recorded sender of message `id`.
+    fn owns_message(&self, id: U256, addr: Address) -> bool {
+        self.senders.get(id) == addr
+    }
+
+    /// Revert with `NotOwner` unless the caller is the contract owner.
+    fn require_owner(&self) -> Result<(), Vec<u8>> {
+        let caller = msg::sender();
+        if self.owner.get() != caller {
+            return Err(NotOwner { caller }.abi_encode());
+        }
+        Ok(())
+    }
+
+    /// Revert with `ContractPaused` while the owner has paused the contract.
+    fn require_not_paused(&self) -> Result<(), Vec<u8>> {
+        if self.paused.get() {
+            return Err(ContractPaused {}.abi_encode());
+        }
+        Ok(())
+    }
+
+    /// Emit `Milestone` if `count` reaches the next pending milestone.
+    ///
+    /// With no configured list this is pure arithmetic; otherwise it reads
+    /// only the next pending entry and advances past it once reached.
+    fn check_milestone(&mut self, count: U256) {
+        let len = self.milestones.len();
+        if len == 0 {
+            if is_power_of_ten(count) {
+                evm::log(Milestone { count });
+            }
+            return;
+        }
+
+        let next = self.next_milestone.get();
+        if next >= U256::from(len) {
+            return;
+        }
+        if self.milestones.get(next).is_some_and(|m| count >= m) {
+            self.next_milestone.set(next + U256::from(1));
+            evm::log(Milestone { count });
+        }
+    }
+
+    /// Revert with `InvalidDestination` for the zero address, or with
+    /// `DestinationNotAllowed` if whitelisting is on and `destination` is not
+    /// approved.
+    fn require_bridge_destination(&self, destination: Address) -> Result<(), Vec<u8>> {
+        if destination.is_zero() {
+            return Err(InvalidDestination {}.abi_encode());
+        }
+        if self.bridge_restricted.get() && !self.bridge_allowed.get(destination) {
+            return Err(DestinationNotAllowed { destination }.abi_encode());
+        }
+        Ok(())
+    }
+
+    /// Revert with `ContractFrozen` once the owner has called `freeze`.
+    fn require_not_frozen(&self) -> Result<(), Vec<u8>> {
+        if self.frozen.get() {
+            return Err(ContractFrozen {}.abi_encode());
+        }
+        Ok(())
+    }
+
+    /// Revert with `NotAllowlisted` if allowlist mode is on and `sender` is
+    /// not approved. With the mode off this is a single flag read.
+    fn require_allowed(&self, sender: Address) -> Result<(), Vec<u8>> {
+        if self.allowlist_enabled.get() && !self.allowed.get(sender) {
+            return Err(NotAllowlisted { sender }.abi_encode());
+        }
+        Ok(())
+    }
+
+    /// Revert with `SenderBlocked` if the owner has banned `addr`.
+    fn require_not_blocked(&self, addr: Address) -> Result<(), Vec<u8>> {
+        if self.blocked.get(addr) {
+            return Err(SenderBlocked { sender: addr }.abi_encode());
+        }
+        Ok(())
+    }
+
+    /// Revert with `QuotaExceeded` if sending `messages` more would take
+    /// `sender` past `max_per_sender`. A quota of 0 means unlimited.
+    fn require_quota(&self, sender: Address, messages: usize) -> Result<(), Vec<u8>> {
+        let max = self.max_per_sender.get();
+        if max.is_zero() {
+            return Ok(());
+        }
+        let used = self.sent_counts.get(sender);
+        if used.saturating_add(U256::from(messages)) > max {
+            return Err(QuotaExceeded { used, max }.abi_encode());
+        }
+        Ok(())
+    }
+
+    /// Add `paid` to `collected_fees` and emit `FeeCollected` against `id`.
+    /// Does nothing when no value was attached.
+    fn record_fee(&mut self, id: U256, paid: U256) {
+        if paid.is_zero() {
+            return;
+        }
+        let total = self.collected_fees.get();
+        self.collected_fees.set(total + paid);
+        evm::log(FeeCollected { id, amount: paid });
+    }
+
+    /// Revert with `InsufficientFee` unless the attached value covers
+    /// `send_fee` for `messages` messages. Returns the value paid.
+    ///
+    /// A total that overflows can never be covered, so it always reverts and
+    /// is reported as `U256::MAX`.
+    fn require_fee(&self, messages: usize) -> Result<U256, Vec<u8>> {
+        let required = self.send_fee.get().checked_mul(U256::from(messages));
+        let provided = msg::value();
+        match required {
+            Some(required) if provided >= required => Ok(provided),
+            _ => Err(InsufficientFee {
+                required: required.unwrap_or(U256::MAX),
+                provided,
+            }
+            .abi_encode()),
+        }
+    }
+
+    /// Revert with `BatchTooLarge` if `len` exceeds `MAX_BATCH_SIZE`.
+    fn require_batch_size(&self, len: usize) -> Result<(), Vec<u8>> {
+        let requested = U256::from(len);
+        let max = U256::from(MAX_BATCH_SIZE);
+        if requested > max {
+            return Err(BatchTooLarge { requested, max }.abi_encode());
+        }
+        Ok(())
+    }
+
+    /// Run the full `send_message` flow for a message from the caller:
+    /// access checks, fee, validation, storage, and fee accounting.
+    fn send_from_caller(&mut self, content: String) -> Result<U256, Vec<u8>> {
+        self.send_as(msg::sender(), content)
+    }
+
+    /// Run the `send_message` checks and store `content` with `sender` as its
+    /// author. The fee is taken from `msg::value()`.
+    fn send_as(&mut self, sender: Address, content: String) -> Result<U256, Vec<u8>> {
+        self.require_not_paused()?;
+        self.require_not_frozen()?;
+        self.require_allowed(sender)?;
+        self.require_not_blocked(sender)?;
+        self.require_quota(sender, 1)?;
+        let paid = self.require_fee(1)?;
+        let (content, hash) = self.prepare_content(content)?;
+        self.apply_rate_limit(sender)?;
+
+        let id = self.store_message(sender, content, hash);
+        self.record_fee(id, paid);
+
+        Ok(id)
+    }
+
+    /// Check message content against the send rules and prepend the
+    /// namespace `prefix`, returning the string to store and its
+    /// `content_hash`.
+    ///
+    /// Emptiness is checked before the prefix is added (and skipped while
+    /// `allow_empty` is set), counting whitespace-only content as empty when
+    /// `reject_whitespace` is on; length is always checked on the combined
+    /// string, in bytes rather than chars, since bytes are what drive storage
+    /// cost. With `validate_utf8` on, control characters in the caller's
+    /// content revert with `InvalidEncoding`. Content whose final hash is in
+    /// `banned_hashes` reverts with `ContentBanned`.
+    fn prepare_content(&self, content: String) -> Result<(String, U256), Vec<u8>> {
+        let blank = if self.reject_whitespace.get() {
+            content.trim().is_empty()
+        } else {
+            content.is_empty()
+        };
+        if blank && !self.allow_empty.get() {
+            return Err(EmptyMessage {}.abi_encode());
+        }
+        if self.validate_utf8.get() {
+            if let Some(position) = control_char_offset(&content) {
+                return Err(InvalidEncoding {
+                    position: U256::from(position),
+                }
+                .abi_encode());
+            }
+        }
+        let prefix = self.prefix.get_string();
+        let content = if prefix.is_empty() {
+            content
+        } else {
+            prefix + &content
+        };
+
+        let length = U256::from(content.len());
+        let max = self.max_length();
+        if length > max {
+            return Err(MessageTooLong { length, max }.abi_encode());
+        }
+
+        let hash = content_hash(&content);
+        if self.banned_hashes.get(hash) {
+            return Err(ContentBanned { hash }.abi_encode());
+        }
+        Ok((content, hash))
+    }
+
+    /// Store the content of a newly allocated message `id`, adding its length
+    /// to `total_content_bytes`. The slot is known to be empty, so unlike
+    /// `write_content` this skips reading the old length.
+    fn insert_content(&mut self, id: U256, content: &str) {
+        self.messages.setter(id).set_str(content);
+        let length = U256::from(content.len());
+        let total = self.total_content_bytes.get();
+        self.total_content_bytes.set(total + length);
+    }
+
+    /// Overwrite the content of message `id`, keeping `total_content_bytes`
+    /// in step.
+    fn write_content(&mut self, id: U256, content: &str) {
+        let mut stored = self.messages.setter(id);
+        let previous = U256::from(stored.len());
+        stored.set_str(content);
+        let length = U256::from(content.len());
+        let total = self.total_content_bytes.get().saturating_sub(previous);
+        self.total_content_bytes.set(total + length);
+    }
+
+    /// Allocate the next ID and persist a validated message.
+    ///
+    /// Records `sender`, content, and the current block timestamp, indexes the
+    /// ID under its sender, and emits `MessageSent`. `hash` is the content
+    /// hash returned by `prepare_content`. Callers are responsible for
+    /// validation and access checks.
+    fn store_message(&mut self, sender: Address, content: String, hash: U256) -> U256 {
+        // Allocate the next ID and advance the counter.
+        let id = self.message_count.get();
+        self.message_count.set(id + U256::from(1));
+
+        // Persist message content and its hash, sender address, and timestamp.
+        let timestamp = U256::from(block::timestamp());
+        self.insert_content(id, &content);
+        self.content_hashes.setter(id).set(hash);
+        self.senders.setter(id).set(sender);
+        self.timestamps.setter(id).set(timestamp);
+
+        // Index the ID under its sender (element write plus length bump) and
+        // record it as the sender's latest.
+        self.messages_by_sender.setter(sender).push(id);
+        self.latest_by_sender.setter(sender).set(id + U256::from(1));
+
+        // Count the post against the sender's quota.
+        let mut sent = self.sent_counts.setter(sender);
+        let previous = sent.get();
+        sent.set(previous + U256::from(1));
+
+        // Count and announce the sender the first time they post.
+        if !self.seen_sender.get(sender) {
+            self.seen_sender.setter(sender).set(true);
+            let unique = self.unique_senders.get();
+            self.unique_senders.set(unique + U256::from(1));
+            evm::log(FirstMessageFromSender { sender, id });
+        }
+
+        // Bucket the message under the current block for analytics.
+        let mut bucket = self.block_counts.setter(U256::from(block::number()));
+        let in_block = bucket.get();
+        bucket.set(in_block + U256::from(1));
+
+        // Fold the message into the rolling digest; see `current_digest`.
+        let prev_digest = self.digest.get();
+        let packed = (prev_digest, id, sender, content.as_str()).abi_encode_params();
+        self.digest.set(crypto::keccak(packed).into());
+
+        // Signal when the new count reaches a growth milestone.
+        self.check_milestone(id + U256::from(1));
+
+        // Flag, but still accept, content over the soft limit.
+        let soft_limit = self.soft_limit.get();
+        let length = U256::from(content.len());
+        if !soft_limit.is_zero() && length > soft_limit {
+            evm::log(MessageOverSoftLimit { id, length });
+        }
+
+        // Emit Solidity-compatible event for indexers.
+        if self.omit_content.get() {
+            evm::log(MessageSentLite { id, sender });
+        } else {
+            evm::log(MessageSent {
+                id,
+                sender,
+                content,
+                timestamp,
+            });
+        }
+
+        id
+    }
+
+    /// Enforce `min_block_gap` for `sender` and record the current block as
+    /// its latest post.
+    ///
+    /// With the limit disabled nothing is read or written, so the send path
+    /// costs the same as before rate limiting existed.
+    fn apply_rate_limit(&mut self, sender: Address) -> Result<(), Vec<u8>> {
+        let gap = self.min_block_gap.get();
+        if gap.is_zero() {
+            return Ok(());
+        }
+
+        let now = U256::from(block::number());
+        let last = self.last_post_block.get(sender);
+        if !last.is_zero() && now < last.saturating_add(gap) {
+            return Err(RateLimited {
+                nextAllowedBlock: last + gap,
+            }
+            .abi_encode());
+        }
+
+        self.last_post_block.setter(sender).set(now);
+        Ok(())
+    }
+
+    /// Bridge stored message `id` to `destination` using the given payload
+    /// `encoding` (and `selector`, for `ENCODING_CALL`) while holding the
+    /// `locked` reentrancy guard.
+    ///
+    /// The guard is released whether or not the bridge succeeds, so a failed
+    /// bridge never leaves the function locked.
+    fn bridge_stored(
+        &mut self,
+        id: U256,
+        destination: Address,
+        encoding: u8,
+        selector: FixedBytes<4>,
+    ) -> Result<U256, Vec<u8>> {
+        self.acquire_lock()?;
+        let result = self.bridge_unlocked(id, destination, encoding, selector, msg::value());
+        self.locked.set(false);
+        result
+    }
+
+    /// Take the `locked` reentrancy guard; the caller must clear it again.
+    fn acquire_lock(&mut self) -> Result<(), Vec<u8>> {
+        if self.locked.get() {
+            return Err(Reentrancy {}.abi_encode());
+        }
+        self.locked.set(true);
+        Ok(())
+    }
+
+    /// Validate and bridge every message in `ids` to the caller, then record
+    /// the tickets and emit one `MessageBridged` per message.
+    ///
+    /// Callers must hold the `locked` guard; see `bridge_messages`.
+    #[allow(deprecated)]
+    fn bridge_batch_unlocked(&mut self, ids: &[U256]) -> Result<Vec<U256>, Vec<u8>> {
+        self.require_not_paused()?;
+        self.require_not_blocked(msg::sender())?;
+        let destination = msg::sender();
+        self.require_bridge_destination(destination)?;
+
+        // Validate the whole batch and read every payload before calling out.
+        let mut payloads = Vec::with_capacity(ids.len());
+        for (i, &id) in ids.iter().enumerate() {
+            self.require_live(id)?;
+            if self.bridged.get(id) || ids[..i].contains(&id) {
+                return Err(AlreadyBridged { id }.abi_encode());
+            }
+            payloads.push(self.bridge_payload(id, ENCODING_RAW, FixedBytes::ZERO));
+        }
+
+        // CRITICAL: flush the storage cache once before the cross-contract calls.
+        unsafe {
+            stylus_sdk::storage::StorageCache::flush();
+        }
+
+        let arbsys = self.arbsys_addr();
+        let mut tickets = Vec::with_capacity(ids.len());
+        for (i, (&id, data)) in ids.iter().zip(payloads).enumerate() {
+            let ticket_id = call_arbsys(arbsys, destination, data, U256::ZERO)
+                .map_err(|err| bridge_call_failed(i, err))?;
+            self.record_bridge_block(ticket_id);
+
+            self.bridge_tickets.setter(id).set(ticket_id);
+            self.bridged.setter(id).set(true);
+            let bridge_tx_hash: [u8; 32] = ticket_id.to_be_bytes();
+            evm::log(MessageBridged {
+                id,
+                destination,
+                bridgeTxHash: bridge_tx_hash.into(),
+                encoding: ENCODING_RAW,
+                selector: FixedBytes::ZERO,
+            });
+            tickets.push(ticket_id);
+        }
+        Ok(tickets)
+    }
+
+    /// Validate message `id` and bridge it to every address in
+    /// `destinations`, then record the first ticket and emit one
+    /// `MessageBridged` per destination.
+    ///
+    /// Callers must hold the `locked` guard; see `bridge_message_multi`.
+    #[allow(deprecated)]
+    fn bridge_multi_unlocked(
+        &mut self,
+        id: U256,
+        destinations: &[Address],
+    ) -> Result<Vec<U256>, Vec<u8>> {
+        self.require_not_paused()?;
+        self.require_not_blocked(msg::sender())?;
+        for &destination in destinations {
+            self.require_bridge_destination(destination)?;
+        }
+        self.require_live(id)?;
+        if self.bridged.get(id) {
+            return Err(AlreadyBridged { id }.abi_encode());
+        }
+        let data = self.bridge_payload(id, ENCODING_RAW, FixedBytes::ZERO);
+
+        // CRITICAL: flush the storage cache once before the cross-contract calls.
+        unsafe {
+            stylus_sdk::storage::StorageCache::flush();
+        }
+
+        let arbsys = self.arbsys_addr();
+        let mut tickets = Vec::with_capacity(destinations.len());
+        for (i, &destination) in destinations.iter().enumerate() {
+            let ticket_id = call_arbsys(arbsys, destination, data.clone(), U256::ZERO)
+                .map_err(|err| bridge_call_failed(i, err))?;
+            self.record_bridge_block(ticket_id);
+
+            let bridge_tx_hash: [u8; 32] = ticket_id.to_be_bytes();
+            evm::log(MessageBridged {
+                id,
+                destination,
+                bridgeTxHash: bridge_tx_hash.into(),
+                encoding: ENCODING_RAW,
+                selector: FixedBytes::ZERO,
+            });
+            tickets.push(ticket_id);
+        }
+
+        if let Some(&first) = tickets.first() {
+            self.bridge_tickets.setter(id).set(first);
+            self.bridged.setter(id).set(true);
+        }
+        Ok(tickets)
+    }
+
+    /// Bridge stored message `id` to `destination` using the given payload
+    /// `encoding`, forwarding `value`, then record the ticket and emit
+    /// `MessageBridged`.
+    ///
+    /// Callers must hold the `locked` guard; see `bridge_stored`.
+    fn bridge_unlocked(
+        &mut self,
+        id: U256,
+        destination: Address,
+        encoding: u8,
+        selector: FixedBytes<4>,
+        value: U256,
+    ) -> Result<U256, Vec<u8>> {
+        self.require_not_paused()?;
+        self.require_not_blocked(msg::sender())?;
+        self.require_bridge_destination(destination)?;
+
+        // Verify the message exists before doing any external work.
+        self.require_live(id)?;
+        if self.bridged.get(id) {
+            return Err(AlreadyBridged { id }.abi_encode());
+        }
+
+        // Read message content while we still hold the storage cache.
+        let data = self.bridge_payload(id, encoding, selector);
+
+        let ticket_id = self.send_to_l1(destination, data, value)?;
+
+        // Convert the returned ticket ID (U256) to a bytes32 for the event.
+        let bridge_tx_hash: [u8; 32] = ticket_id.to_be_bytes();
+
+        self.bridge_tickets.setter(id).set(ticket_id);
+        self.bridged.setter(id).set(true);
+
+        evm::log(MessageBridged {
+            id,
+            destination,
+            bridgeTxHash: bridge_tx_hash.into(),
+            encoding,
+            selector,
+        });
+        if !value.is_zero() {
+            evm::log(BridgeValueForwarded { id, value });
+        }
+
+        Ok(ticket_id)
+    }
+
+    /// Build the L1 payload for message `id` in the given `encoding`.
+    fn bridge_payload(&self, id: U256, encoding: u8, selector: FixedBytes<4>) -> Vec<u8> {
+        let content = self.messages.getter(id).get_string();
+        match encoding {
+            ENCODING_ABI => (id, self.senders.get(id), content).abi_encode_params(),
+            ENCODING_CALL => [selector.as_slice(), &(id, content).abi_encode_params()].concat(),
+            _ => content.into_bytes(),
+        }
+    }
+
+    /// Invoke `ArbSys.sendTxToL1(destination, data)` with `value` attached and
+    /// return the ticket ID.
+    ///
+    /// # Safety
+    /// Uses the deprecated `StorageCache::flush()` before the cross-contract call,
+    /// which is required by the Stylus SDK to prevent storage aliasing during
+    /// reentrant or cross-contract execution.
+    ///
+    /// # Errors
+    /// Reverts with `BridgeCallFailed` if the ArbSys call reverts.
+    #[allow(deprecated)]
+    fn send_to_l1(
+        &mut self,
+        destination: Address,
+        data: Vec<u8>,
+        value: U256,
+    ) -> Result<U256, Vec<u8>> {
+        // CRITICAL: flush the storage cache before any cross-contract call.
+        // The Stylus SDK requires this to ensure storage writes are committed
+        // before control transfers to another contract.
+        unsafe {
+            stylus_sdk::storage::StorageCache::flush();
+        }
+
+        let ticket_id = call_arbsys(self.arbsys_addr(), destination, data, value)
+            .map_err(|err| bridge_call_failed(0, err))?;
+        self.record_bridge_block(ticket_id);
+        Ok(ticket_id)
+    }
+
+    /// Remember the current block against `ticket_id` for
+    /// `bridge_ready_estimate`.
+    fn record_bridge_block(&mut self, ticket_id: U256) {
+        self.bridge_blocks
+            .setter(ticket_id)
+            .set(U256::from(block::number()));
+    }
+}
+
+/// Call `sendTxToL1(destination, data)` on the ArbSys contract at `arbsys`
+/// with `value` attached.
+///
+/// Does not flush the storage cache; callers are responsible for that.
+#[allow(deprecated)]
+fn call_arbsys(
+    arbsys: Address,
+    destination: Address,
+    data: Vec<u8>,
+    value: U256,
+) -> Result<U256, call::Error> {
+    let arbsys = IArbSys::new(arbsys);
+    let config = call::Call::new().value(value);
+    arbsys.send_tx_to_l_1(config, destination, Bytes::from(data))
+}
+
+/// Encode a failed ArbSys call at batch position `index` as `BridgeCallFailed`.
+///
+/// `reason` carries the callee's raw revert data unchanged so tooling can
+/// decode the underlying Solidity error. If the call succeeded but its return
+/// data could not be decoded, `reason` is a generic `Panic` encoding.
+#[allow(deprecated)]
+fn bridge_call_failed(index: usize, err: call::Error) -> Vec<u8> {
+    let reason: Vec<u8> = err.into();
+    BridgeCallFailed {
+        index: U256::from(index),
+        reason: reason.into(),
+    }
+    .abi_encode()
+}
+
+/// Storage key for per-(message, address) maps: `keccak256(id ++ addr)`.
+fn pair_key(id: U256, addr: Address) -> B256 {
+    let mut packed = [0u8; 52];
+    packed[..32].copy_from_slice(&id.to_be_bytes::<32>());
+    packed[32..].copy_from_slice(addr.as_slice());
+    crypto::keccak(packed)
+}
+
+/// Whether `n` is 10, 100, 1000, ...
+fn is_power_of_ten(mut n: U256) -> bool {
+    let ten = U256::from(10);
+    if n < ten {
+        return false;
+    }
+    while (n % ten).is_zero() {
+        n /= ten;
+    }
+    n == U256::from(1)
+}
+
+/// Byte offset of the first control character in `content`, ignoring tab,
+/// CR, and LF.
+fn control_char_offset(content: &str) -> Option<usize> {
+    content
+        .char_indices()
+        .find(|&(_, c)| c.is_control() && !matches!(c, '\t' | '\r' | '\n'))
+        .map(|(offset, _)| offset)
+}
+
+/// keccak256 of message content as stored in `content_hashes`.
+fn content_hash(content: &str) -> U256 {
+    U256::from_be_bytes(crypto::keccak(content).0)
+}
+
+/// Recover the address that produced the EIP-191 (`personal_sign`)
+/// `signature` over `digest`, via the ecrecover precompile.
+///
+/// Returns `None` for malformed signatures or when recovery fails.
+#[allow(deprecated)]
+fn recover_signer(digest: B256, signature: &[u8]) -> Option<Address> {
+    if signature.len() != 65 {
+        return None;
+    }
+    let v = match signature[64] {
+        0 | 1 => signature[64] + 27,
+        27 | 28 => signature[64],
+        _ => return None,
+    };
+
+    let mut prefixed = Vec::with_capacity(28 + 32);
+    prefixed.extend_from_slice(b"\x19Ethereum Signed Message:\n32");
+    prefixed.extend_from_slice(digest.as_slice());
+    let hash = crypto::keccak(prefixed);
+
+    // ecrecover input: hash || v || r || s, each a 32-byte word.
+    let mut input = [0u8; 128];
+    input[..32].copy_from_slice(hash.as_slice());
+    input[63] = v;
+    input[64..].copy_from_slice(&signature[..64]);
+
+    let output = call::static_call(call::Call::new(), ECRECOVER_ADDR, &input).ok()?;
+    if output.len() != 32 {
+        return None;
+    }
+    let signer = Address::from_slice(&output[12..]);
+    (!signer.is_zero()).then_some(signer)
+}
+
+#[cfg(test)]
+mod tests;
@@ -0,0 +1,221 @@
+//! Unit tests run natively against an in-memory stand-in for the Stylus host.
+//!
+//! The SDK imports its host functions from the `vm_hooks` module, which on a
+//! native target are plain `extern "C"` symbols. `mock_host` defines them over
+//! a per-thread storage map with a fixed caller, block, and zero call value.
+//! The SDK caches the caller and block number for the whole process, so tests
+//! simulate time passing by writing expiry blocks directly.
+
+use super::*;
+use stylus_sdk::host::{WasmVM, VM};
+
+/// Caller address returned by the mock `msg_sender`.
+const CALLER: Address = Address::new([0x11; 20]);
+
+/// Block number returned by the mock `block_number`.
+const BLOCK: u64 = 1_000;
+
+#[allow(clippy::missing_safety_doc)]
+mod mock_host {
+    use super::{BLOCK, CALLER};
+    use alloy_primitives::Keccak256;
+    use std::cell::RefCell;
+    use std::collections::HashMap;
+
+    std::thread_local! {
+        static STORAGE: RefCell<HashMap<[u8; 32], [u8; 32]>> = RefCell::new(HashMap::new());
+    }
+
+    unsafe fn write_word(dest: *mut u8, word: &[u8; 32]) {
+        core::ptr::copy_nonoverlapping(word.as_ptr(), dest, 32);
+    }
+
+    unsafe fn read_word(src: *const u8) -> [u8; 32] {
+        let mut word = [0u8; 32];
+        core::ptr::copy_nonoverlapping(src, word.as_mut_ptr(), 32);
+        word
+    }
+
+    #[no_mangle]
+    pub unsafe extern "C" fn storage_load_bytes32(key: *const u8, dest: *mut u8) {
+        let key = read_word(key);
+        let value = STORAGE.with(|s| s.borrow().get(&key).copied().unwrap_or_default());
+        write_word(dest, &value);
+    }
+
+    #[no_mangle]
+    pub unsafe extern "C" fn storage_cache_bytes32(key: *const u8, value: *const u8) {
+        let (key, value) = (read_word(key), read_word(value));
+        STORAGE.with(|s| s.borrow_mut().insert(key, value));
+    }
+
+    #[no_mangle]
+    pub extern "C" fn storage_flush_cache(_clear: bool) {}
+
+    #[no_mangle]
+    pub unsafe extern "C" fn native_keccak256(bytes: *const u8, len: usize, output: *mut u8) {
+        let mut hasher = Keccak256::new();
+        hasher.update(core::slice::from_raw_parts(bytes, len));
+        write_word(output, &hasher.finalize().0);
+    }
+
+    #[no_mangle]
+    pub unsafe extern "C" fn msg_sender(sender: *mut u8) {
+        core::ptr::copy_nonoverlapping(CALLER.as_ptr(), sender, 20);
+    }
+
+    #[no_mangle]
+    pub unsafe extern "C" fn msg_value(value: *mut u8) {
+        write_word(value, &[0u8; 32]);
+    }
+
+    #[no_mangle]
+    pub extern "C" fn msg_reentrant() -> bool {
+        false
+    }
+
+    #[no_mangle]
+    pub extern "C" fn block_number() -> u64 {
+        BLOCK
+    }
+
+    #[no_mangle]
+    pub extern "C" fn block_timestamp() -> u64 {
+        1_700_000_000
+    }
+
+    #[no_mangle]
+    pub extern "C" fn emit_log(_data: *const u8, _len: usize, _topics: usize) {}
+}
+
+/// A fresh contract over empty storage, owned by `CALLER`.
+fn contract() -> MessagingContract {
+    let mut c = unsafe { MessagingContract::new(U256::ZERO, 0, VM(WasmVM {})) };
+    c.init(CALLER).unwrap();
+    c
+}
+
+#[test]
+fn sends_and_reads_back() {
+    let mut c = contract();
+    let id = c.send_message("hello".into()).unwrap();
+    assert_eq!(c.get_message(id).unwrap(), "hello");
+}
+
+#[test]
+fn freeze_blocks_import() {
+    let mut c = contract();
+    let sender = Address::new([0x22; 20]);
+    c.import_message(U256::ZERO, sender, "before".into(), U256::ZERO)
+        .unwrap();
+
+    c.freeze().unwrap();
+    let err = c
+        .import_message(U256::from(1), sender, "after".into(), U256::ZERO)
+        .unwrap_err();
+    assert_eq!(err, ContractFrozen {}.abi_encode());
+    assert_eq!(c.message_count(), U256::from(1));
+}
+
+#[test]
+fn import_rejects_max_id() {
+    let mut c = contract();
+    let sender = Address::new([0x22; 20]);
+    let err = c
+        .import_message(U256::MAX, sender, "last".into(), U256::ZERO)
+        .unwrap_err();
+    assert_eq!(err, IdOutOfRange { id: U256::MAX }.abi_encode());
+    assert_eq!(c.message_count(), U256::ZERO);
+}
+
+#[test]
+fn proof_data_of_pruned_message_has_zero_hash() {
+    let mut c = contract();
+    let id = c
+        .send_message_expiring("short-lived".into(), U256::from(1))
+        .unwrap();
+    c.expiry.setter(id).set(U256::from(BLOCK - 1));
+    c.prune(id).unwrap();
+
+    let (sender, hash) = c.get_proof_data(id).unwrap();
+    assert_eq!(sender, CALLER);
+    assert_eq!(hash, U256::ZERO);
+    assert_eq!(hash, c.get_content_hash(id).unwrap());
+}
+
+#[test]
+fn bulk_views_hide_expired_content() {
+    let mut c = contract();
+    let expiring = c
+        .send_message_expiring("short-lived".into(), U256::from(1))
+        .unwrap();
+    let live = c.send_message("still here".into()).unwrap();
+    c.expiry.setter(expiring).set(U256::from(BLOCK - 1));
+
+    let page = c.get_messages(U256::ZERO, U256::from(10)).unwrap();
+    assert_eq!(page.len(), 2);
+    assert_eq!(page[0], (expiring, CALLER, String::new()));
+    assert_eq!(page[1], (live, CALLER, "still here".into()));
+    assert_eq!(c.get_recent(U256::from(10)).unwrap(), page);
+
+    let from = c
+        .get_messages_from(CALLER, U256::ZERO, U256::from(10))
+        .unwrap();
+    assert_eq!(
+        from,
+        vec![(expiring, String::new()), (live, "still here".into())]
+    );
+
+    let lookup = c.lookup_message(expiring);
+    assert!(lookup.found);
+    assert!(lookup.content.is_empty());
+    let export = c.export_range(U256::ZERO, U256::from(1)).unwrap();
+    assert!(export[0].2.is_empty());
+}
+
+#[test]
+fn stored_hash_matches_prefixed_content() {
+    let mut c = contract();
+    c.set_prefix("ns:".into()).unwrap();
+    let id = c.send_message("body".into()).unwrap();
+    assert_eq!(c.get_content_hash(id).unwrap(), content_hash("ns:body"));
+}
+
+#[test]
+fn storage_estimate_tracks_sends_and_edits() {
+    let mut c = contract();
+    let id = c.send_message("abcd".into()).unwrap();
+    c.send_message("xy".into()).unwrap();
+    let overhead = U256::from(2 * MESSAGE_OVERHEAD_BYTES);
+    assert_eq!(c.estimated_storage_bytes(), overhead + U256::from(6));
+
+    c.edit_message(id, "a".into()).unwrap();
+    assert_eq!(c.estimated_storage_bytes(), overhead + U256::from(3));
+}
+
+#[test]
+fn quota_counts_sends_but_not_imports() {
+    let mut c = contract();
+    assert_eq!(c.sends_remaining(CALLER), U256::MAX);
+    c.send_message("one".into()).unwrap();
+    c.import_message(U256::from(100), CALLER, "imported".into(), U256::ZERO)
+        .unwrap();
+
+    c.set_max_per_sender(U256::from(2)).unwrap();
+    assert_eq!(c.sends_remaining(CALLER), U256::from(1));
+    c.send_message("two".into()).unwrap();
+    let err = c.send_message("three".into()).unwrap_err();
+    let used = U256::from(2);
+    let max = U256::from(2);
+    assert_eq!(err, QuotaExceeded { used, max }.abi_encode());
+}
+
+#[test]
+fn overflowing_batch_fee_reverts() {
+    let mut c = contract();
+    c.set_send_fee(U256::MAX).unwrap();
+    let err = c.send_messages(vec!["a".into(), "b".into()]).unwrap_err();
+    let required = U256::MAX;
+    let provided = U256::ZERO;
+    assert_eq!(err, InsufficientFee { required, provided }.abi_encode());
+}
@@ -6,7 +6,7 @@
  *   get_message(uint256) -> string
  *   get_sender(uint256) -> address
  *   message_count() -> uint256
- *   bridge_message(uint256)
+ *   bridge_message(uint256) -> uint256
  *
  * Events: MessageSent, MessageBridged
  * Errors: MessageNotFound, BridgeCallFailed, EmptyMessage
@@ -18,7 +18,7 @@ export const messagingContractAbi = [
     name: 'sendMessage',
     inputs: [{ name: 'content', type: 'string' }],
     outputs: [{ name: '', type: 'uint256' }],
-    stateMutability: 'nonpayable',
+    stateMutability: 'payable',
   },
   {
     type: 'function',
@@ -45,8 +45,8 @@ export const messagingContractAbi = [
     type: 'function',
     name: 'bridgeMessage',
     inputs: [{ name: 'id', type: 'uint256' }],
-    outputs: [],
-    stateMutability: 'nonpayable',
+    outputs: [{ name: '', type: 'uint256' }],
+    stateMutability: 'payable',
   },
 
   // ── Events ─────────────────────────────────────────────────────────────
@@ -57,6 +57,7 @@ export const messagingContractAbi = [
       { name: 'id', type: 'uint256', indexed: true },
       { name: 'sender', type: 'address', indexed: true },
       { name: 'content', type: 'string', indexed: false },
+      { name: 'timestamp', type: 'uint256', indexed: false },
     ],
   },
   {
@@ -64,7 +65,10 @@ export const messagingContractAbi = [
     name: 'MessageBridged',
     inputs: [
       { name: 'id', type: 'uint256', indexed: true },
+      { name: 'destination', type: 'address', indexed: true },
       { name: 'bridgeTxHash', type: 'bytes32', indexed: false },
+      { name: 'encoding', type: 'uint8', indexed: false },
+      { name: 'selector', type: 'bytes4', indexed: false },
     ],
   },
 
@@ -77,7 +81,10 @@ export const messagingContractAbi = [
   {
     type: 'error',
     name: 'BridgeCallFailed',
-    inputs: [{ name: 'reason', type: 'bytes' }],
+    inputs: [
+      { name: 'index', type: 'uint256' },
+      { name: 'reason', type: 'bytes' },
+    ],
   },
   {
     type: 'error',
@@ -0,0 +1,72 @@
+#!/bin/bash
+# =============================================================================
+# check-wasm-size.sh — Fail if a Stylus contract outgrows its deploy budget
+#
+# Builds each contract's release WASM and compresses it the way cargo-stylus
+# does before deployment (brotli, quality 11, window 22), then compares the
+# compressed size against MAX_CODE_SIZE (default 64 KiB, the MaxCodeSize set
+# in scripts/deploy-chain.ts; Arbitrum's default is 24 KiB).
+#
+# Prerequisites:
+#   - Rust toolchain with wasm32-unknown-unknown target
+#   - Node.js (for brotli compression)
+#
+# Usage:
+#   ./scripts/check-wasm-size.sh                 # All Stylus contracts
+#   ./scripts/check-wasm-size.sh messaging       # A single contract
+# =============================================================================
+set -euo pipefail
+
+SCRIPT_DIR="$(cd "$(dirname "${BASH_SOURCE[0]}")" && pwd)"
+PROJECT_ROOT="$(cd "$SCRIPT_DIR/.." && pwd)"
+
+MAX_CODE_SIZE="${MAX_CODE_SIZE:-65536}"
+
+if [ "$#" -gt 0 ]; then
+  CONTRACTS=("$@")
+else
+  CONTRACTS=(messaging compute-stylus)
+fi
+
+FAILED=false
+
+for name in "${CONTRACTS[@]}"; do
+  dir="$PROJECT_ROOT/contracts/$name"
+  if [ ! -f "$dir/Cargo.toml" ]; then
+    echo "ERROR: Contract not found at $dir/Cargo.toml"
+    exit 1
+  fi
+
+  if ! build_log=$(cd "$dir" && cargo build --release --lib --target wasm32-unknown-unknown 2>&1); then
+    echo "$build_log"
+    echo "ERROR: Build failed for $name"
+    exit 1
+  fi
+
+  crate=$(sed -n 's/^name = "\(.*\)"$/\1/p' "$dir/Cargo.toml" | head -1)
+  wasm="$dir/target/wasm32-unknown-unknown/release/${crate//-/_}.wasm"
+
+  read -r raw compressed < <(node -e "
+    const zlib = require('zlib');
+    const wasm = require('fs').readFileSync(process.argv[1]);
+    const packed = zlib.brotliCompressSync(wasm, { params: {
+      [zlib.constants.BROTLI_PARAM_QUALITY]: 11,
+      [zlib.constants.BROTLI_PARAM_LGWIN]: 22,
+    } });
+    console.log(wasm.length, packed.length);
+  " "$wasm")
+
+  if [ "$compressed" -gt "$MAX_CODE_SIZE" ]; then
+    status="OVER"
+    FAILED=true
+  else
+    status="OK"
+  fi
+  printf "  %-20s %8d bytes raw  %7d bytes compressed  (budget %d)  %s\n" \
+    "$name" "$raw" "$compressed" "$MAX_CODE_SIZE" "$status"
+done
+
+if [ "$FAILED" = true ]; then
+  echo "ERROR: One or more contracts exceed MAX_CODE_SIZE ($MAX_CODE_SIZE bytes compressed)."
+  exit 1
+fi
@@ -3,6 +3,7 @@
 #
 # Prerequisites:
 #   - Rust toolchain with wasm32-unknown-unknown target
+#   - Node.js (for the compressed size check)
 #   - cargo-stylus CLI installed (cargo install cargo-stylus)
 #   - .env file with CONTRACT_DEPLOYER_KEY and L2_CHAIN_RPC set
 #   - L2 chain running and reachable at L2_CHAIN_RPC
@@ -50,20 +51,25 @@ echo "Contract: $CONTRACT_DIR"
 echo ""
 
 # Step 1: Export ABI for verification
-echo "[1/3] Exporting contract ABI..."
+echo "[1/4] Exporting contract ABI..."
 cargo run --features export-abi --target-dir /tmp/omega-abi 2>/dev/null || echo "  (ABI export skipped — verify manually against frontend/src/abi/MessagingContract.ts)"
 echo ""
 
-# Step 2: Check WASM validity
-echo "[2/3] Checking Stylus contract (cargo stylus check)..."
+# Step 2: Check compressed WASM size against the chain's code size limit
+echo "[2/4] Checking compressed WASM size..."
+"$SCRIPT_DIR/check-wasm-size.sh" messaging
+echo ""
+
+# Step 3: Check WASM validity
+echo "[3/4] Checking Stylus contract (cargo stylus check)..."
 cd "$CONTRACT_DIR"
 cargo generate-lockfile 2>/dev/null || true
 cargo stylus check --endpoint "$L2_CHAIN_RPC" 2>&1
 echo "Check passed."
 echo ""
 
-# Step 3: Deploy and capture contract address
-echo "[3/3] Deploying contract (cargo stylus deploy)..."
+# Step 4: Deploy and capture contract address
+echo "[4/4] Deploying contract (cargo stylus deploy)..."
 DEPLOY_OUTPUT=$(cargo stylus deploy \
     --private-key "$CONTRACT_DEPLOYER_KEY" \
     --endpoint "$L2_CHAIN_RPC" \
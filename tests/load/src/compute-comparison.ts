@@ -1,9 +1,10 @@
 /**
  * Compute Comparison: Stylus WASM vs EVM — Iterated Keccak256
  *
- * Runs iterated keccak256 hashing with minimal storage (1 SSTORE per call) to
- * isolate WASM computation cost from storage overhead. Stylus's ink metering
- * should produce a clear gas advantage for pure computation workloads.
+ * Runs iterated keccak256 hashing with minimal storage (1 SSTORE per call on
+ * EVM; Stylus also stores the last hash, for 2 single-slot SSTOREs) to isolate
+ * WASM computation cost from storage overhead. Stylus's ink metering should
+ * produce a clear gas advantage for pure computation workloads.
  *
  * Iteration tiers: [100, 500, 1000, 2000]
  * Fixed burst size: 100 TXs per tier